@@ -0,0 +1,61 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use sqids::Sqids;
+
+const SIZES: [usize; 3] = [100, 10_000, 100_000];
+
+fn encode(c: &mut Criterion) {
+	let sqids = Sqids::default();
+	let mut group = c.benchmark_group("encode");
+
+	for size in SIZES {
+		let numbers: Vec<u64> = (0..size as u64).collect();
+		group.throughput(Throughput::Elements(size as u64));
+
+		group.bench_with_input(BenchmarkId::new("sequential", size), &numbers, |b, numbers| {
+			b.iter(|| numbers.iter().map(|&number| sqids.encode(&[number])).collect::<Vec<_>>())
+		});
+		group.bench_with_input(BenchmarkId::new("encode_each", size), &numbers, |b, numbers| {
+			b.iter(|| sqids.encode_each(numbers))
+		});
+	}
+}
+
+fn encode_batch(c: &mut Criterion) {
+	let sqids = Sqids::default();
+	let mut group = c.benchmark_group("encode_batch");
+
+	for size in SIZES {
+		let numbers: Vec<[u64; 3]> = (0..size as u64).map(|i| [i, i * 7, i * 13]).collect();
+		let batch: Vec<&[u64]> = numbers.iter().map(|numbers| &numbers[..]).collect();
+		group.throughput(Throughput::Elements(size as u64));
+
+		group.bench_with_input(BenchmarkId::new("sequential", size), &batch, |b, batch| {
+			b.iter(|| batch.iter().map(|numbers| sqids.encode(numbers)).collect::<Vec<_>>())
+		});
+		group.bench_with_input(BenchmarkId::new("encode_batch", size), &batch, |b, batch| {
+			b.iter(|| sqids.encode_batch(batch))
+		});
+	}
+}
+
+fn decode_batch(c: &mut Criterion) {
+	let sqids = Sqids::default();
+	let mut group = c.benchmark_group("decode_batch");
+
+	for size in SIZES {
+		let ids: Vec<String> =
+			(0..size as u64).map(|i| sqids.encode(&[i, i * 7, i * 13]).unwrap()).collect();
+		let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+		group.throughput(Throughput::Elements(size as u64));
+
+		group.bench_with_input(BenchmarkId::new("sequential", size), &ids, |b, ids| {
+			b.iter(|| ids.iter().map(|id| sqids.try_decode(id)).collect::<Vec<_>>())
+		});
+		group.bench_with_input(BenchmarkId::new("decode_batch", size), &ids, |b, ids| {
+			b.iter(|| sqids.decode_batch(ids))
+		});
+	}
+}
+
+criterion_group!(benches, encode, encode_batch, decode_batch);
+criterion_main!(benches);
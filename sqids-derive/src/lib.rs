@@ -0,0 +1,219 @@
+//! Derive macro for typed [sqids](https://docs.rs/sqids) IDs.
+//!
+//! This crate is re-exported by `sqids` with the `derive` feature enabled and should not be used
+//! directly.
+
+#![warn(missing_docs)]
+#![allow(clippy::tabs_in_doc_comments)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitInt, LitStr, Type};
+
+/// Derive `Display`, `FromStr`, `Serialize`, `Deserialize` and `SqidsConfig` for a tuple struct
+/// of `u64` fields, encoding all fields into a single sqid.
+///
+/// The [Sqids](https://docs.rs/sqids/latest/sqids/struct.Sqids.html) instance is built lazily
+/// on first use from the optional `#[sqid(...)]` attribute:
+///
+/// - `alphabet = "..."`: custom alphabet
+/// - `min_length = 8`: minimum length of the sqid, without the prefix
+/// - `prefix = "usr_"`: prefix that must end with a character that is not in the alphabet
+/// - `registry = "users"`: use the `Sqids` registered under this name instead of building one,
+///   which requires the `registry` feature of `sqids` and cannot be combined with `alphabet` or
+///   `min_length`. Parsing returns `Error::NotRegistered` when nothing is registered under the
+///   name, and `SqidsConfig::sqids` panics. Since the registered alphabet is only known at
+///   runtime, parsing returns `Error::PrefixDelimiter` when the prefix ends with a character of
+///   that alphabet
+///
+/// Parsing only accepts canonical sqids with the configured prefix, and returns `Error::Arity`
+/// for sqids of a different count of numbers than the fields. Formatting fails, so `to_string`
+/// panics, when the sqid cannot be generated: when the blocklist blocks every sqid of the
+/// fields, or when nothing is registered under the `registry` name.
+///
+/// ```
+/// # use sqids::Sqid;
+/// #[derive(Debug, PartialEq, Sqid)]
+/// #[sqid(min_length = 8, prefix = "usr_")]
+/// struct UserId(u64);
+///
+/// assert_eq!(UserId(1).to_string(), "usr_UkLWZg9D");
+/// assert_eq!("usr_UkLWZg9D".parse(), Ok(UserId(1)));
+/// ```
+///
+/// The prefix has to be delimited from the sqid:
+///
+/// ```compile_fail
+/// # use sqids::Sqid;
+/// #[derive(Sqid)]
+/// #[sqid(prefix = "usr")]
+/// struct UserId(u64);
+/// ```
+#[proc_macro_derive(Sqid, attributes(sqid))]
+pub fn derive_sqid(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+
+	expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+	let name = &input.ident;
+
+	if !input.generics.params.is_empty() {
+		return Err(Error::new_spanned(
+			&input.generics,
+			"#[derive(Sqid)] does not support generics",
+		));
+	}
+
+	let fields = match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Unnamed(fields) if !fields.unnamed.is_empty() => &fields.unnamed,
+			_ => return Err(Error::new_spanned(name, "#[derive(Sqid)] requires a tuple struct")),
+		},
+		_ => return Err(Error::new_spanned(name, "#[derive(Sqid)] requires a tuple struct")),
+	};
+	for field in fields {
+		if !matches!(&field.ty, Type::Path(ty) if ty.path.is_ident("u64")) {
+			return Err(Error::new_spanned(&field.ty, "#[derive(Sqid)] fields must be `u64`"));
+		}
+	}
+
+	let mut alphabet: Option<LitStr> = None;
+	let mut min_length: Option<LitInt> = None;
+	let mut prefix: Option<LitStr> = None;
+	let mut registry: Option<LitStr> = None;
+	for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("sqid")) {
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("alphabet") {
+				alphabet = Some(meta.value()?.parse()?);
+			} else if meta.path.is_ident("min_length") {
+				let value: LitInt = meta.value()?.parse()?;
+				value.base10_parse::<u8>()?;
+				min_length = Some(value);
+			} else if meta.path.is_ident("prefix") {
+				prefix = Some(meta.value()?.parse()?);
+			} else if meta.path.is_ident("registry") {
+				registry = Some(meta.value()?.parse()?);
+			} else {
+				return Err(meta.error("unknown #[sqid] option"));
+			}
+			Ok(())
+		})?;
+	}
+
+	if let Some(registry) = &registry {
+		if alphabet.is_some() || min_length.is_some() {
+			return Err(Error::new_spanned(
+				registry,
+				"`registry` cannot be combined with `alphabet` or `min_length`",
+			));
+		}
+	}
+
+	if let Some(prefix) = &prefix {
+		// the alphabet of a registered Sqids is checked when it is looked up
+		let delimited = match prefix.value().chars().last() {
+			Some(_) if registry.is_some() => true,
+			Some(delimiter) => match &alphabet {
+				Some(alphabet) => !alphabet.value().contains(delimiter),
+				None => !delimiter.is_ascii_alphanumeric(),
+			},
+			None => false,
+		};
+		if !delimited {
+			return Err(Error::new_spanned(
+				prefix,
+				"prefix must end with a delimiter that is not in the alphabet",
+			));
+		}
+	}
+
+	// formatting and parsing look up a registered Sqids without panicking
+	let lookup = match (&registry, &prefix) {
+		(Some(registry), Some(prefix)) => quote! {
+			::sqids::registry::get(#registry)
+				.ok_or(::sqids::Error::NotRegistered)
+				.and_then(|sqids| ::sqids::__private::check_prefix(sqids, #prefix))
+		},
+		(Some(registry), None) => quote! {
+			::sqids::registry::get(#registry).ok_or(::sqids::Error::NotRegistered)
+		},
+		(None, _) => quote! {
+			::std::result::Result::<_, ::sqids::Error>::Ok(<Self as ::sqids::SqidsConfig>::sqids())
+		},
+	};
+	let sqids = match registry {
+		Some(registry) => quote! {
+			::sqids::registry::get(#registry)
+				.expect(concat!("no Sqids registered as ", stringify!(#registry)))
+		},
+		None => {
+			let alphabet = alphabet.map(|alphabet| quote!(.alphabet(#alphabet.chars().collect())));
+			let min_length = min_length.map(|min_length| quote!(.min_length(#min_length)));
+			quote! {
+				static SQIDS: ::std::sync::OnceLock<::sqids::Sqids> = ::std::sync::OnceLock::new();
+				SQIDS.get_or_init(|| {
+					::sqids::Sqids::builder()
+						#alphabet
+						#min_length
+						.build()
+						.expect(concat!("invalid #[sqid] configuration of ", stringify!(#name)))
+				})
+			}
+		}
+	};
+	let prefix = prefix.unwrap_or_else(|| LitStr::new("", Span::call_site()));
+	let indices = (0..fields.len()).map(syn::Index::from);
+	let bindings: Vec<_> = (0..fields.len()).map(|i| format_ident!("n{i}")).collect();
+	let types = fields.iter().map(|field| &field.ty);
+
+	Ok(quote! {
+		impl ::sqids::SqidsConfig for #name {
+			fn sqids() -> &'static ::sqids::Sqids {
+				#sqids
+			}
+		}
+
+		impl ::std::fmt::Display for #name {
+			fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+				let id = #lookup
+					.and_then(|sqids| sqids.encode_value(&(#(self.#indices,)*)))
+					.map_err(|_| ::std::fmt::Error)?;
+				f.write_str(#prefix)?;
+				f.write_str(&id)
+			}
+		}
+
+		impl ::std::str::FromStr for #name {
+			type Err = ::sqids::Error;
+
+			fn from_str(id: &str) -> ::std::result::Result<Self, Self::Err> {
+				let id = id.strip_prefix(#prefix).ok_or(::sqids::Error::PrefixUnknown)?;
+				let (#(#bindings,)*) = #lookup?.decode_value::<(#(#types,)*)>(id)?;
+				Ok(Self(#(#bindings),*))
+			}
+		}
+
+		impl ::sqids::__private::serde::Serialize for #name {
+			fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+			where
+				S: ::sqids::__private::serde::Serializer,
+			{
+				serializer.collect_str(self)
+			}
+		}
+
+		impl<'de> ::sqids::__private::serde::Deserialize<'de> for #name {
+			fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+			where
+				D: ::sqids::__private::serde::Deserializer<'de>,
+			{
+				let id: ::std::string::String =
+					::sqids::__private::serde::Deserialize::deserialize(deserializer)?;
+				id.parse().map_err(<D::Error as ::sqids::__private::serde::de::Error>::custom)
+			}
+		}
+	})
+}
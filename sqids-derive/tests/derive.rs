@@ -0,0 +1,93 @@
+use sqids::{Error, Sqid, SqidsConfig};
+
+#[derive(Debug, PartialEq, Sqid)]
+struct PlainId(u64);
+
+#[derive(Debug, PartialEq, Sqid)]
+#[sqid(alphabet = "0123456789abcdef", min_length = 8, prefix = "usr_")]
+struct UserId(u64);
+
+#[derive(Debug, PartialEq, Sqid)]
+#[sqid(prefix = "itm_")]
+struct ItemId(u64, u64, u64);
+
+#[test]
+fn plain() {
+	assert_eq!(PlainId(1).to_string(), "Uk");
+	assert_eq!("Uk".parse(), Ok(PlainId(1)));
+	assert_eq!(PlainId::sqids().encode(&[1]).unwrap(), "Uk");
+}
+
+#[test]
+fn configured() {
+	let id = UserId(1).to_string();
+
+	assert!(id.starts_with("usr_"));
+	assert_eq!(id.len(), 12);
+	assert!(id[4..].chars().all(|c| c.is_ascii_hexdigit()));
+	assert_eq!(id.parse(), Ok(UserId(1)));
+}
+
+#[test]
+fn multiple_fields() {
+	let id = ItemId(1, 2, 3);
+
+	assert_eq!(id.to_string(), "itm_86Rf07");
+	assert_eq!("itm_86Rf07".parse(), Ok(id));
+}
+
+#[test]
+fn parse_errors() {
+	assert_eq!("86Rf07".parse::<ItemId>(), Err(Error::PrefixUnknown));
+	assert_eq!("usr_86Rf07".parse::<ItemId>(), Err(Error::PrefixUnknown));
+	assert_eq!("itm_se8ojk".parse::<ItemId>(), Err(Error::NonCanonicalId));
+	assert_eq!("itm_Uk".parse::<ItemId>(), Err(Error::Arity { expected: 3, actual: 1 }));
+	assert_eq!("86Rf07".parse::<PlainId>(), Err(Error::Arity { expected: 1, actual: 3 }));
+	assert_eq!("itm_".parse::<ItemId>(), Err(Error::InvalidId));
+	assert_eq!("itm_*".parse::<ItemId>(), Err(Error::InvalidId));
+}
+
+#[test]
+fn serde() {
+	let id = ItemId(1, 2, 3);
+
+	assert_eq!(serde_json::to_string(&id).unwrap(), "\"itm_86Rf07\"");
+	assert_eq!(serde_json::from_str::<ItemId>("\"itm_86Rf07\"").unwrap(), id);
+	assert!(serde_json::from_str::<ItemId>("\"itm_se8ojk\"").is_err());
+	assert!(serde_json::from_str::<ItemId>("1").is_err());
+}
+
+#[derive(Debug, PartialEq, Sqid)]
+#[sqid(registry = "orders", prefix = "ord_")]
+struct OrderId(u64);
+
+#[test]
+fn registered() {
+	sqids::register("orders", sqids::Sqids::builder().min_length(8).build().unwrap()).unwrap();
+
+	assert_eq!(OrderId(1).to_string(), "ord_UkLWZg9D");
+	assert_eq!("ord_UkLWZg9D".parse(), Ok(OrderId(1)));
+	assert_eq!("ord_Uk".parse::<OrderId>(), Err(Error::NonCanonicalId));
+}
+
+#[derive(Debug, PartialEq, Sqid)]
+#[sqid(registry = "carts", prefix = "cart0")]
+struct CartId(u64);
+
+#[test]
+fn registered_prefix_delimiter() {
+	sqids::register("carts", sqids::Sqids::default()).unwrap();
+
+	assert_eq!("cart086Rf07".parse::<CartId>(), Err(Error::PrefixDelimiter));
+	assert!(std::fmt::write(&mut String::new(), format_args!("{}", CartId(1))).is_err());
+}
+
+#[derive(Debug, PartialEq, Sqid)]
+#[sqid(registry = "invoices")]
+struct InvoiceId(u64);
+
+#[test]
+fn not_registered() {
+	assert_eq!("Uk".parse::<InvoiceId>(), Err(Error::NotRegistered));
+	assert!(std::fmt::write(&mut String::new(), format_args!("{}", InvoiceId(1))).is_err());
+}
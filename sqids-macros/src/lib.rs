@@ -0,0 +1,138 @@
+//! Compile-time [sqids](https://docs.rs/sqids) encoding and decoding, for well-known IDs that are
+//! hard-coded in source.
+//!
+//! Both macros take the configuration as `key = value` pairs before their input:
+//!
+//! - `alphabet = "..."`: custom alphabet
+//! - `min_length = 10`: minimum length of the sqid
+//! - `blocklist = ["..."]`: custom blocklist instead of the default one
+//! - `check_character = true`: append a check character
+//!
+//! To keep the configuration in one place, wrap the macros in a `macro_rules!` macro:
+//!
+//! ```
+//! macro_rules! my_sqid {
+//! 	($($number:expr),*) => {
+//! 		sqids_macros::sqids!(min_length = 10, [$($number),*])
+//! 	};
+//! }
+//!
+//! const SYSTEM_USER: &str = my_sqid!(1, 2, 3);
+//! assert_eq!(SYSTEM_USER, "86Rf07xd4z");
+//! ```
+
+#![warn(missing_docs)]
+#![allow(clippy::tabs_in_doc_comments)]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use sqids::Sqids;
+use syn::{
+	parse::{Parse, ParseStream},
+	parse_macro_input,
+	punctuated::Punctuated,
+	Error, ExprArray, Ident, LitBool, LitInt, LitStr, Token,
+};
+
+/// Encode numbers into a sqid at compile time, expanding to a `&'static str`.
+///
+/// ```
+/// use sqids_macros::sqids;
+///
+/// const SYSTEM_USER: &str = sqids!([1, 2, 3]);
+/// assert_eq!(SYSTEM_USER, "86Rf07");
+/// assert_eq!(sqids!(min_length = 10, [1, 2, 3]), "86Rf07xd4z");
+/// ```
+#[proc_macro]
+pub fn sqids(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as MacroInput<ExprArray>);
+
+	let numbers = match input.value.elems.iter().map(parse_number).collect::<syn::Result<Vec<_>>>()
+	{
+		Ok(numbers) => numbers,
+		Err(error) => return error.into_compile_error().into(),
+	};
+
+	match input.config.and_then(|sqids| {
+		sqids.encode(&numbers).map_err(|error| Error::new_spanned(&input.value, error))
+	}) {
+		Ok(id) => quote!(#id).into(),
+		Err(error) => error.into_compile_error().into(),
+	}
+}
+
+/// Decode a canonical sqid at compile time, expanding to a `[u64; N]` array.
+///
+/// ```
+/// use sqids_macros::sqid_numbers;
+///
+/// const SYSTEM_USER: [u64; 3] = sqid_numbers!("86Rf07");
+/// assert_eq!(SYSTEM_USER, [1, 2, 3]);
+/// ```
+///
+/// Invalid sqids fail to compile:
+///
+/// ```compile_fail
+/// const SYSTEM_USER: [u64; 3] = sqids_macros::sqid_numbers!("86Rf_07");
+/// ```
+#[proc_macro]
+pub fn sqid_numbers(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as MacroInput<LitStr>);
+
+	let numbers =
+		input.config.and_then(|sqids| match sqids.decode_canonical(&input.value.value()) {
+			Ok(numbers) if !numbers.is_empty() => Ok(numbers),
+			Ok(_) => Err(Error::new_spanned(&input.value, sqids::Error::InvalidId)),
+			Err(error) => Err(Error::new_spanned(&input.value, error)),
+		});
+
+	match numbers {
+		Ok(numbers) => quote!([#(#numbers),*]).into(),
+		Err(error) => error.into_compile_error().into(),
+	}
+}
+
+struct MacroInput<T> {
+	config: syn::Result<Sqids>,
+	value: T,
+}
+
+impl<T: Parse> Parse for MacroInput<T> {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let mut builder = Sqids::builder();
+		let span = input.span();
+
+		while input.peek(Ident) && input.peek2(Token![=]) {
+			let key: Ident = input.parse()?;
+			input.parse::<Token![=]>()?;
+
+			builder = match key.to_string().as_str() {
+				"alphabet" => builder.alphabet(input.parse::<LitStr>()?.value().chars().collect()),
+				"min_length" => builder.min_length(input.parse::<LitInt>()?.base10_parse()?),
+				"blocklist" => {
+					let content;
+					syn::bracketed!(content in input);
+					let words = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+					builder.blocklist(words.iter().map(LitStr::value).collect())
+				}
+				"check_character" => builder.check_character(input.parse::<LitBool>()?.value),
+				_ => return Err(Error::new_spanned(key, "unknown sqids option")),
+			};
+			input.parse::<Token![,]>()?;
+		}
+
+		let value = input.parse()?;
+		input.parse::<Option<Token![,]>>()?;
+
+		Ok(MacroInput { config: builder.build().map_err(|error| Error::new(span, error)), value })
+	}
+}
+
+fn parse_number(expr: &syn::Expr) -> syn::Result<u64> {
+	match expr {
+		syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(number), .. }) => number.base10_parse(),
+		// literals passed through `macro_rules!` fragments arrive wrapped in a group
+		syn::Expr::Group(group) => parse_number(&group.expr),
+		_ => Err(Error::new_spanned(expr, "expected an integer literal")),
+	}
+}
@@ -0,0 +1,43 @@
+use sqids::Sqids;
+use sqids_macros::{sqid_numbers, sqids};
+
+const SYSTEM_USER: &str = sqids!([1, 2, 3]);
+const SYSTEM_USER_NUMBERS: [u64; 3] = sqid_numbers!("86Rf07");
+
+#[test]
+fn simple() {
+	assert_eq!(SYSTEM_USER, "86Rf07");
+	assert_eq!(SYSTEM_USER_NUMBERS, [1, 2, 3]);
+	assert_eq!(Sqids::default().decode(SYSTEM_USER), SYSTEM_USER_NUMBERS);
+}
+
+#[test]
+fn config() {
+	let sqids = Sqids::builder()
+		.alphabet("0123456789abcdef".chars().collect())
+		.min_length(8)
+		.check_character(true)
+		.build()
+		.unwrap();
+
+	let id = sqids!(
+		alphabet = "0123456789abcdef",
+		min_length = 8,
+		check_character = true,
+		[0, 18_446_744_073_709_551_615, 100]
+	);
+
+	assert_eq!(id, sqids.encode(&[0, u64::MAX, 100]).unwrap());
+}
+
+#[test]
+fn blocklist() {
+	assert_eq!(sqids!(blocklist = ["86Rf07"], [1, 2, 3]), "se8ojk");
+	assert_eq!(sqid_numbers!(blocklist = ["86Rf07"], "se8ojk"), [1, 2, 3]);
+}
+
+#[test]
+fn min_length() {
+	assert_eq!(sqids!(min_length = 10, [1, 2, 3]), "86Rf07xd4z");
+	assert_eq!(sqid_numbers!(min_length = 10, "86Rf07xd4z"), [1, 2, 3]);
+}
@@ -0,0 +1,136 @@
+//! [actix-web](https://docs.rs/actix-web) extractor for sqid parameters.
+//!
+//! [SqidParam] reads a sqid from a path segment, or else from the query string, and decodes it
+//! with [Sqids::decode_value] using the app's `web::Data<Sqids>`. Failures are reported as a
+//! [SqidParamError], a `404 Not Found` for a sqid that does not decode and a `400 Bad Request`
+//! for a missing parameter.
+//!
+//! ```
+//! # use actix_web::{web, App};
+//! # use sqids::{actix::{SqidParam, SqidParamConfig}, Sqids};
+//! async fn order(SqidParam(id): SqidParam<u64>) -> String {
+//! 	format!("order {id}")
+//! }
+//!
+//! async fn items(SqidParam((order_id, item_id)): SqidParam<(u64, u64)>) -> String {
+//! 	format!("item {item_id} of order {order_id}")
+//! }
+//!
+//! let app = App::new()
+//! 	.app_data(web::Data::new(Sqids::default()))
+//! 	// `/orders/Uk`
+//! 	.route("/orders/{id}", web::get().to(order))
+//! 	// `/items?item=<sqid>`
+//! 	.service(
+//! 		web::resource("/items")
+//! 			.app_data(SqidParamConfig::default().name("item"))
+//! 			.route(web::get().to(items)),
+//! 	);
+//! ```
+
+use std::{collections::HashMap, future::Ready};
+
+use ::actix_web::{dev::Payload, http::StatusCode, web, FromRequest, HttpRequest, ResponseError};
+
+use crate::{Error, FromSqidNumbers, Sqid, Sqids, SqidsConfig};
+
+/// Extracts a value of type `T` from a sqid parameter, see the [module](self) documentation.
+///
+/// The parameter is looked up by the name set with [SqidParamConfig], first in the path and
+/// then in the query string. `T` can be any [FromSqidNumbers], like `u64` or a tuple of `u64`s,
+/// decoded with the registered [Sqids], or a typed [Sqid](struct@Sqid), decoded with the
+/// [Sqids] of its [SqidsConfig] (see [integrations](struct@Sqid#integrations)).
+#[derive(Debug)]
+pub struct SqidParam<T>(pub T);
+
+impl<T> SqidParam<T> {
+	/// The extracted value.
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T: FromSqidNumbers> FromRequest for SqidParam<T> {
+	type Error = SqidParamError;
+	type Future = Ready<Result<Self, SqidParamError>>;
+
+	fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+		std::future::ready(extract(req).map(SqidParam))
+	}
+}
+
+impl<T: SqidsConfig> FromRequest for SqidParam<Sqid<T>> {
+	type Error = SqidParamError;
+	type Future = Ready<Result<Self, SqidParamError>>;
+
+	fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+		let id = param(req).and_then(|id| Sqid::decode(&id).map_err(SqidParamError::InvalidId));
+		std::future::ready(id.map(SqidParam))
+	}
+}
+
+fn extract<T: FromSqidNumbers>(req: &HttpRequest) -> Result<T, SqidParamError> {
+	let sqids = req.app_data::<web::Data<Sqids>>().ok_or(SqidParamError::MissingSqids)?;
+	sqids.decode_value(&param(req)?).map_err(SqidParamError::InvalidId)
+}
+
+/// The sqid parameter, from the path or else from the query string.
+fn param(req: &HttpRequest) -> Result<String, SqidParamError> {
+	let name = req.app_data::<SqidParamConfig>().map_or(DEFAULT_NAME, |config| &config.name);
+
+	if let Some(id) = req.match_info().get(name) {
+		return Ok(id.to_string());
+	}
+
+	let mut query = web::Query::<HashMap<String, String>>::from_query(req.query_string())
+		.map_err(|_| SqidParamError::Missing)?;
+	query.remove(name).ok_or(SqidParamError::Missing)
+}
+
+const DEFAULT_NAME: &str = "id";
+
+/// Configuration of [SqidParam], set with `app_data` on the app, a scope or a resource.
+#[derive(Clone, Debug)]
+pub struct SqidParamConfig {
+	name: String,
+}
+
+impl SqidParamConfig {
+	/// Set the name of the parameter holding the sqid, `id` by default.
+	pub fn name(mut self, name: impl Into<String>) -> Self {
+		self.name = name.into();
+		self
+	}
+}
+
+impl Default for SqidParamConfig {
+	fn default() -> Self {
+		SqidParamConfig { name: DEFAULT_NAME.to_string() }
+	}
+}
+
+/// Error returned by [SqidParam].
+#[derive(Debug, thiserror::Error)]
+pub enum SqidParamError {
+	/// No `web::Data<Sqids>` is registered, rendered as `500 Internal Server Error`.
+	#[error("No Sqids is registered as app data")]
+	MissingSqids,
+	/// The parameter is neither in the path nor in the query string, rendered as
+	/// `400 Bad Request`.
+	#[error("Missing sqid parameter")]
+	Missing,
+	/// The parameter is not a canonical sqid of the expected numbers, rendered as
+	/// `404 Not Found`.
+	#[error("Invalid sqid: {0}")]
+	InvalidId(Error),
+}
+
+impl ResponseError for SqidParamError {
+	fn status_code(&self) -> StatusCode {
+		match self {
+			SqidParamError::MissingSqids => StatusCode::INTERNAL_SERVER_ERROR,
+			SqidParamError::Missing => StatusCode::BAD_REQUEST,
+			SqidParamError::InvalidId(_) => StatusCode::NOT_FOUND,
+		}
+	}
+}
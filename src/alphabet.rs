@@ -0,0 +1,93 @@
+//! Helpers for deriving [Sqids](crate::Sqids) alphabets.
+
+/// Commonly used alphabets that can be passed to [SqidsBuilder::alphabet](crate::SqidsBuilder).
+///
+/// ```
+/// # use sqids::{Alphabet, Sqids};
+/// let sqids = Sqids::builder().alphabet(Alphabet::Crockford32.into()).build()?;
+/// let id = sqids.encode(&[1, 2, 3])?;
+/// assert!(id.chars().all(|c| Alphabet::Crockford32.as_str().contains(c)));
+/// # Ok::<(), sqids::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Alphabet {
+	/// Lowercase letters and digits, for case-insensitive systems.
+	Lowercase,
+	/// Crockford's base32: uppercase letters and digits without `I`, `L`, `O` and `U`.
+	Crockford32,
+	/// Letters and digits without the easily confused `0`, `O`, `o`, `1`, `I`, `i` and `l`.
+	Unambiguous,
+	/// The QR code alphanumeric set without the space character.
+	QrAlphanumeric,
+	/// Letters, digits, `-` and `_`.
+	UrlSafe64,
+}
+
+impl Alphabet {
+	/// All available presets.
+	pub const ALL: [Alphabet; 5] = [
+		Alphabet::Lowercase,
+		Alphabet::Crockford32,
+		Alphabet::Unambiguous,
+		Alphabet::QrAlphanumeric,
+		Alphabet::UrlSafe64,
+	];
+
+	/// The characters of this alphabet.
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Alphabet::Lowercase => "abcdefghijklmnopqrstuvwxyz0123456789",
+			Alphabet::Crockford32 => "0123456789ABCDEFGHJKMNPQRSTVWXYZ",
+			Alphabet::Unambiguous => "abcdefghjkmnpqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ23456789",
+			Alphabet::QrAlphanumeric => "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ$%*+-./:",
+			Alphabet::UrlSafe64 => {
+				"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789-_"
+			}
+		}
+	}
+}
+
+impl From<Alphabet> for Vec<char> {
+	fn from(alphabet: Alphabet) -> Self {
+		alphabet.as_str().chars().collect()
+	}
+}
+
+/// Deterministically permute `alphabet` using `seed` as the key, see
+/// [SqidsBuilder::alphabet_from_seed](crate::SqidsBuilder::alphabet_from_seed).
+pub(crate) fn permute_from_seed(alphabet: &[char], seed: &[u8]) -> Vec<char> {
+	let mut chars = alphabet.to_vec();
+	let mut state = fnv1a(seed);
+
+	for i in (1..chars.len()).rev() {
+		let j = (splitmix64(&mut state) % (i as u64 + 1)) as usize;
+		chars.swap(i, j);
+	}
+
+	chars
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+	bytes.iter().fold(0xcbf29ce484222325, |hash, &b| (hash ^ b as u64).wrapping_mul(0x100000001b3))
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+	*state = state.wrapping_add(0x9e3779b97f4a7c15);
+	let mut z = *state;
+	z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+	z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+	z ^ (z >> 31)
+}
+
+/// Returns a random permutation of [DEFAULT_ALPHABET](crate::DEFAULT_ALPHABET).
+///
+/// The result should be generated once and stored, since IDs can only be decoded with the same
+/// alphabet they were encoded with.
+#[cfg(feature = "rand")]
+pub fn random_alphabet() -> String {
+	use rand::seq::SliceRandom;
+
+	let mut chars: Vec<char> = crate::DEFAULT_ALPHABET.chars().collect();
+	chars.shuffle(&mut rand::rng());
+	chars.into_iter().collect()
+}
@@ -0,0 +1,139 @@
+//! [Arrow](https://docs.rs/arrow) kernels encoding and decoding whole columns of sqids.
+//!
+//! Nulls are preserved: a null input row gives a null output row. With the `rayon` feature, the
+//! sqids of a column are generated in parallel, see `Sqids::encode_batch`.
+//!
+//! ```
+//! # use arrow_array::{cast::AsArray, types::UInt64Type, Array, ListArray, UInt64Array};
+//! # use sqids::{arrow, Sqids};
+//! let sqids = Sqids::default();
+//!
+//! let ids = arrow::encode_array(&sqids, &UInt64Array::from(vec![Some(1), None, Some(2)]))?;
+//! assert_eq!(ids.iter().collect::<Vec<_>>(), [Some("Uk"), None, Some("gb")]);
+//!
+//! let rows = ListArray::from_iter_primitive::<UInt64Type, _, _>([Some([Some(1), Some(2), Some(3)])]);
+//! let ids = arrow::encode_list_array(&sqids, &rows)?;
+//! assert_eq!(ids.value(0), "86Rf07");
+//!
+//! let numbers = arrow::decode_array(&sqids, &ids)?;
+//! assert_eq!(numbers, rows);
+//! # Ok::<(), arrow_schema::ArrowError>(())
+//! ```
+
+use std::{cmp::max, slice};
+
+use arrow_array::{
+	builder::{ListBuilder, StringBuilder, UInt64Builder},
+	cast::AsArray,
+	types::UInt64Type,
+	Array, ListArray, StringArray, UInt64Array,
+};
+use arrow_schema::ArrowError;
+
+use crate::{Error, Sqids};
+
+/// Encode each number of `array` into its own sqid.
+pub fn encode_array(sqids: &Sqids, array: &UInt64Array) -> Result<StringArray, ArrowError> {
+	let rows = (0..array.len())
+		.map(|row| array.is_valid(row).then(|| slice::from_ref(&array.values()[row])));
+
+	encode_rows(sqids, rows, array.len())
+}
+
+/// Encode the numbers of each row of `array`, a list of `UInt64`s without nulls, into one
+/// sqid.
+pub fn encode_list_array(sqids: &Sqids, array: &ListArray) -> Result<StringArray, ArrowError> {
+	let values = array.values().as_primitive_opt::<UInt64Type>().ok_or_else(|| {
+		ArrowError::InvalidArgumentError(format!(
+			"expected a list of UInt64, got a list of {}",
+			array.values().data_type()
+		))
+	})?;
+	if values.null_count() > 0 {
+		return Err(ArrowError::InvalidArgumentError("list values cannot be null".to_string()));
+	}
+
+	let offsets = array.value_offsets();
+	let rows = (0..array.len()).map(|row| {
+		array
+			.is_valid(row)
+			.then(|| &values.values()[offsets[row] as usize..offsets[row + 1] as usize])
+	});
+
+	encode_rows(sqids, rows, array.len())
+}
+
+/// Decode each canonical sqid of `array` into a list of numbers (see
+/// [Sqids::decode_canonical]).
+pub fn decode_array(sqids: &Sqids, array: &StringArray) -> Result<ListArray, ArrowError> {
+	let mut builder =
+		ListBuilder::with_capacity(UInt64Builder::with_capacity(array.len()), array.len());
+
+	for id in array {
+		match id {
+			Some(id) => {
+				let numbers = sqids.decode_canonical(id).map_err(|error| {
+					ArrowError::ComputeError(format!("invalid sqid {id:?}: {error}"))
+				})?;
+				builder.values().append_slice(&numbers);
+				builder.append(true);
+			}
+			None => builder.append_null(),
+		}
+	}
+
+	Ok(builder.finish())
+}
+
+/// Encode the numbers of each row, `None` for a null row, into a column of `len` sqids.
+#[cfg(not(feature = "rayon"))]
+fn encode_rows<'a>(
+	sqids: &Sqids,
+	rows: impl Iterator<Item = Option<&'a [u64]>>,
+	len: usize,
+) -> Result<StringArray, ArrowError> {
+	let mut builder = string_builder(sqids, len);
+
+	for row in rows {
+		match row {
+			Some(numbers) => builder.append_value(sqids.encode(numbers).map_err(compute_error)?),
+			None => builder.append_null(),
+		}
+	}
+
+	Ok(builder.finish())
+}
+
+/// Encode the numbers of each row, `None` for a null row, into a column of `len` sqids, the
+/// sqids of the rows that are not null being generated in parallel.
+#[cfg(feature = "rayon")]
+fn encode_rows<'a>(
+	sqids: &Sqids,
+	rows: impl Iterator<Item = Option<&'a [u64]>>,
+	len: usize,
+) -> Result<StringArray, ArrowError> {
+	let rows: Vec<_> = rows.collect();
+	let batch: Vec<_> = rows.iter().flatten().copied().collect();
+	let mut ids = sqids.encode_batch(&batch).into_iter();
+	let mut builder = string_builder(sqids, len);
+
+	for row in rows {
+		match row {
+			// one sqid for each row that is not null, in order
+			Some(_) => builder.append_value(ids.next().unwrap().map_err(compute_error)?),
+			None => builder.append_null(),
+		}
+	}
+
+	Ok(builder.finish())
+}
+
+/// A builder with room for `len` sqids of the usual length, so that most columns are built
+/// without reallocating.
+fn string_builder(sqids: &Sqids, len: usize) -> StringBuilder {
+	StringBuilder::with_capacity(len, len * max(sqids.min_length as usize, 8))
+}
+
+fn compute_error(error: Error) -> ArrowError {
+	ArrowError::ComputeError(error.to_string())
+}
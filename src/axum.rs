@@ -0,0 +1,153 @@
+//! [axum](https://docs.rs/axum) extractor for sqid path parameters.
+//!
+//! [SqidPath] decodes the path parameter with [Sqids::decode_value], using the [Sqids] from the
+//! router state, which has to provide an `Arc<Sqids>` through [FromRef]. Typed
+//! [Sqid](struct@Sqid)s are decoded with the [Sqids] of their [SqidsConfig] instead (see
+//! [integrations](struct@Sqid#integrations)). Sqids that do not decode are rejected with
+//! `404 Not Found`.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use axum::{routing::get, Router};
+//! # use sqids::{axum::SqidPath, Sqids};
+//! async fn order(id: SqidPath<u64>) -> String {
+//! 	format!("order {}", *id)
+//! }
+//!
+//! async fn item(id: SqidPath<(u64, u64)>) -> String {
+//! 	let (order_id, item_id) = id.into_inner();
+//! 	format!("item {item_id} of order {order_id}")
+//! }
+//!
+//! let app: Router = Router::new()
+//! 	.route("/orders/{id}", get(order))
+//! 	.route("/items/{id}", get(item))
+//! 	.with_state(Arc::new(Sqids::default()));
+//! ```
+
+use std::{
+	marker::PhantomData,
+	ops::{Deref, DerefMut},
+	sync::Arc,
+};
+
+use ::axum::{
+	extract::{rejection::PathRejection, FromRef, FromRequestParts, Path},
+	http::{request::Parts, StatusCode},
+	response::{IntoResponse, Response},
+};
+
+use crate::{Error, FromSqidNumbers, Sqid, Sqids, SqidsConfig};
+
+/// Extracts a value of type `T` from a sqid path parameter, see the [module](self)
+/// documentation.
+///
+/// `T` can be any [FromSqidNumbers], like `u64` or a tuple of `u64`s, or a typed
+/// [Sqid](struct@Sqid). The value is reached through `Deref` or [SqidPath::into_inner]. The
+/// rejection type `R` can be replaced to customize the response:
+///
+/// ```
+/// # use axum::{http::StatusCode, response::{IntoResponse, Response}};
+/// # use sqids::axum::{SqidPath, SqidRejection};
+/// struct ApiError(SqidRejection);
+///
+/// impl From<SqidRejection> for ApiError {
+/// 	fn from(rejection: SqidRejection) -> Self {
+/// 		ApiError(rejection)
+/// 	}
+/// }
+///
+/// impl IntoResponse for ApiError {
+/// 	fn into_response(self) -> Response {
+/// 		(StatusCode::BAD_REQUEST, self.0.to_string()).into_response()
+/// 	}
+/// }
+///
+/// async fn order(id: SqidPath<u64, ApiError>) -> String {
+/// 	format!("order {}", *id)
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SqidPath<T, R = SqidRejection>(T, PhantomData<fn() -> R>);
+
+impl<T, R> SqidPath<T, R> {
+	/// The extracted value.
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T, R> Deref for SqidPath<T, R> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		&self.0
+	}
+}
+
+impl<T, R> DerefMut for SqidPath<T, R> {
+	fn deref_mut(&mut self) -> &mut T {
+		&mut self.0
+	}
+}
+
+impl<S, T, R> FromRequestParts<S> for SqidPath<T, R>
+where
+	S: Send + Sync,
+	Arc<Sqids>: FromRef<S>,
+	T: FromSqidNumbers,
+	R: From<SqidRejection> + IntoResponse,
+{
+	type Rejection = R;
+
+	async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, R> {
+		let Path(id) =
+			Path::<String>::from_request_parts(parts, state).await.map_err(SqidRejection::Path)?;
+
+		let sqids = Arc::<Sqids>::from_ref(state);
+		let value = sqids.decode_value(&id).map_err(SqidRejection::InvalidId)?;
+
+		Ok(SqidPath(value, PhantomData))
+	}
+}
+
+impl<S, T, R> FromRequestParts<S> for SqidPath<Sqid<T>, R>
+where
+	S: Send + Sync,
+	T: SqidsConfig,
+	R: From<SqidRejection> + IntoResponse,
+{
+	type Rejection = R;
+
+	async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, R> {
+		let Path(id) =
+			Path::<String>::from_request_parts(parts, state).await.map_err(SqidRejection::Path)?;
+
+		let value = Sqid::decode(&id).map_err(SqidRejection::InvalidId)?;
+
+		Ok(SqidPath(value, PhantomData))
+	}
+}
+
+/// Rejection used by [SqidPath].
+#[derive(Debug, thiserror::Error)]
+pub enum SqidRejection {
+	/// The path parameter could not be extracted.
+	#[error(transparent)]
+	Path(PathRejection),
+	/// The path parameter is not a canonical sqid of the expected numbers, rendered as
+	/// `404 Not Found`.
+	#[error("Invalid sqid: {0}")]
+	InvalidId(Error),
+}
+
+impl IntoResponse for SqidRejection {
+	fn into_response(self) -> Response {
+		match self {
+			SqidRejection::Path(rejection) => rejection.into_response(),
+			SqidRejection::InvalidId(_) => {
+				(StatusCode::NOT_FOUND, self.to_string()).into_response()
+			}
+		}
+	}
+}
@@ -0,0 +1,47 @@
+use rayon::prelude::*;
+
+use crate::{Result, Sqids};
+
+impl Sqids {
+	/// Generate a sqid for each slice of numbers, in parallel.
+	///
+	/// Results are in input order, with an error for each sqid that cannot be generated (see
+	/// [Sqids::encode]).
+	///
+	/// ```
+	/// # use sqids::Sqids;
+	/// let sqids = Sqids::default();
+	/// let ids = sqids.encode_batch(&[&[1, 2, 3], &[1]]);
+	/// assert_eq!(ids, [Ok("86Rf07".to_string()), Ok("Uk".to_string())]);
+	/// ```
+	pub fn encode_batch(&self, batch: &[&[u64]]) -> Vec<Result<String>> {
+		batch.par_iter().map(|numbers| self.encode(numbers)).collect()
+	}
+
+	/// Generate a sqid for each number, in parallel.
+	///
+	/// ```
+	/// # use sqids::Sqids;
+	/// let sqids = Sqids::default();
+	/// let ids = sqids.encode_each(&[1, 2]);
+	/// assert_eq!(ids, [Ok("Uk".to_string()), Ok("gb".to_string())]);
+	/// ```
+	pub fn encode_each(&self, numbers: &[u64]) -> Vec<Result<String>> {
+		numbers.par_iter().map(|&number| self.encode(&[number])).collect()
+	}
+
+	/// Decode each sqid, in parallel.
+	///
+	/// Results are in input order, with an error for each invalid sqid (see
+	/// [Sqids::try_decode]).
+	///
+	/// ```
+	/// # use sqids::{Error, Sqids};
+	/// let sqids = Sqids::default();
+	/// let numbers = sqids.decode_batch(&["86Rf07", "!"]);
+	/// assert_eq!(numbers, [Ok(vec![1, 2, 3]), Err(Error::InvalidId)]);
+	/// ```
+	pub fn decode_batch(&self, ids: &[&str]) -> Vec<Result<Vec<u64>>> {
+		ids.par_iter().map(|id| self.try_decode(id)).collect()
+	}
+}
@@ -0,0 +1,353 @@
+//! The `const` core of the algorithm, shared by [Sqids](crate::Sqids) and [ConstSqids].
+
+use std::{fmt, ops::Deref};
+
+const DEFAULT_BLOCKLIST_JSON: &str = include_str!("blocklist.json");
+
+/// The words of the default blocklist, parsed at compile time.
+const DEFAULT_BLOCKLIST: [&str; blocklist_len(DEFAULT_BLOCKLIST_JSON)] =
+	parse_blocklist(DEFAULT_BLOCKLIST_JSON);
+
+/// A [Sqids](crate::Sqids) equivalent that can generate sqids in `const` context.
+///
+/// Sqids are the same as the ones generated by a [Sqids](crate::Sqids) with the same alphabet,
+/// minimum length and blocklist. Check characters and grouping are not supported. Invalid
+/// configurations and inputs panic, which fails compilation when evaluated in `const` context.
+///
+/// A [Sqids](crate::Sqids) is still built at runtime, so sqids known at compile time are best
+/// generated into `const` items with a [ConstSqids], which costs nothing at runtime.
+///
+/// ```
+/// # use sqids::{ConstSqid, ConstSqids, DEFAULT_ALPHABET_BYTES};
+/// const SQIDS: ConstSqids<62> = ConstSqids::new(DEFAULT_ALPHABET_BYTES);
+/// const SYSTEM_USER: ConstSqid<16> = SQIDS.encode(&[1, 2, 3]);
+/// const SYSTEM_USER_ID: &str = SYSTEM_USER.as_str();
+///
+/// assert_eq!(SYSTEM_USER_ID, "86Rf07");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ConstSqids<const N: usize> {
+	alphabet: [char; N],
+	min_length: u8,
+	blocklist: &'static [&'static str],
+}
+
+impl<const N: usize> ConstSqids<N> {
+	/// Create a [ConstSqids] with the default blocklist and no minimum length.
+	///
+	/// Panics if the alphabet is shorter than 3 characters, contains non-ASCII characters or
+	/// contains a character more than once.
+	pub const fn new(alphabet: &[u8; N]) -> Self {
+		assert!(N >= 3, "Alphabet length must be at least 3");
+
+		let mut chars = ['\0'; N];
+		let mut i = 0;
+		while i < N {
+			assert!(alphabet[i].is_ascii(), "Alphabet cannot contain multibyte characters");
+			let mut j = i + 1;
+			while j < N {
+				assert!(alphabet[i] != alphabet[j], "Alphabet must contain unique characters");
+				j += 1;
+			}
+			chars[i] = alphabet[i] as char;
+			i += 1;
+		}
+		shuffle(&mut chars);
+
+		ConstSqids { alphabet: chars, min_length: 0, blocklist: &DEFAULT_BLOCKLIST }
+	}
+
+	/// Set the minimum length of a sqid.
+	pub const fn min_length(mut self, min_length: u8) -> Self {
+		self.min_length = min_length;
+		self
+	}
+
+	/// Replace the default blocklist.
+	pub const fn blocklist(mut self, blocklist: &'static [&'static str]) -> Self {
+		self.blocklist = blocklist;
+		self
+	}
+
+	/// Generate a sqid from a slice of numbers, see [Sqids::encode](crate::Sqids::encode).
+	///
+	/// Panics if the sqid is longer than `L` or all possible sqids for the input are blocked.
+	pub const fn encode<const L: usize>(&self, numbers: &[u64]) -> ConstSqid<L> {
+		let mut increment = 0;
+
+		while increment <= N {
+			let id = self.encode_attempt::<L>(numbers, increment);
+			if !self.is_blocked_id(&id) {
+				return id;
+			}
+			increment += 1;
+		}
+
+		panic!("Reached max attempts to re-generate the ID")
+	}
+
+	const fn encode_attempt<const L: usize>(
+		&self,
+		numbers: &[u64],
+		increment: usize,
+	) -> ConstSqid<L> {
+		let mut id = ConstSqid { bytes: [0; L], len: 0 };
+		if numbers.is_empty() {
+			return id;
+		}
+
+		let mut offset = numbers.len();
+		let mut i = 0;
+		while i < numbers.len() {
+			offset += offset_term(&self.alphabet, i, numbers[i]);
+			i += 1;
+		}
+		offset = (offset % N + increment) % N;
+
+		// the rotated alphabet in reverse
+		let mut alphabet = ['\0'; N];
+		i = 0;
+		while i < N {
+			alphabet[i] = self.alphabet[(offset + N - 1 - i) % N];
+			i += 1;
+		}
+
+		id.push(self.alphabet[offset]);
+
+		i = 0;
+		while i < numbers.len() {
+			id.push_number(numbers[i], alphabet.split_at(1).1);
+
+			if i < numbers.len() - 1 {
+				id.push(alphabet[0]);
+				shuffle(&mut alphabet);
+			}
+			i += 1;
+		}
+
+		if self.min_length as usize > id.len {
+			id.push(alphabet[0]);
+
+			while self.min_length as usize > id.len {
+				shuffle(&mut alphabet);
+
+				let mut j = 0;
+				while j < N && self.min_length as usize > id.len {
+					id.push(alphabet[j]);
+					j += 1;
+				}
+			}
+		}
+
+		id
+	}
+
+	const fn is_blocked_id<const L: usize>(&self, id: &ConstSqid<L>) -> bool {
+		let id = id.bytes.split_at(id.len).0;
+
+		let mut i = 0;
+		while i < self.blocklist.len() {
+			let word = self.blocklist[i].as_bytes();
+			if self.is_in_alphabet(word) && is_blocked_by(id, word) {
+				return true;
+			}
+			i += 1;
+		}
+
+		false
+	}
+
+	/// Whether `word` is made of alphabet characters, ignoring case, like the words the
+	/// [Sqids](crate::Sqids) builder keeps in its blocklist.
+	const fn is_in_alphabet(&self, word: &[u8]) -> bool {
+		if word.len() < 3 {
+			return false;
+		}
+
+		let mut i = 0;
+		while i < word.len() {
+			let mut j = 0;
+			while j < N && !(self.alphabet[j] as u8).eq_ignore_ascii_case(&word[i]) {
+				j += 1;
+			}
+			if j == N {
+				return false;
+			}
+			i += 1;
+		}
+
+		true
+	}
+}
+
+/// A sqid generated by [ConstSqids], holding up to `L` characters.
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ConstSqid<const L: usize> {
+	bytes: [u8; L],
+	len: usize,
+}
+
+impl<const L: usize> ConstSqid<L> {
+	/// The sqid as a string slice.
+	pub const fn as_str(&self) -> &str {
+		match std::str::from_utf8(self.bytes.split_at(self.len).0) {
+			Ok(id) => id,
+			Err(_) => unreachable!(),
+		}
+	}
+
+	const fn push(&mut self, c: char) {
+		assert!(self.len < L, "ConstSqid capacity is too small for the ID");
+		self.bytes[self.len] = c as u8;
+		self.len += 1;
+	}
+
+	const fn push_number(&mut self, num: u64, alphabet: &[char]) {
+		let mut digits = ['\0'; 64];
+		let mut i = to_digits(num, alphabet, &mut digits);
+		while i < digits.len() {
+			self.push(digits[i]);
+			i += 1;
+		}
+	}
+}
+
+impl<const L: usize> Deref for ConstSqid<L> {
+	type Target = str;
+
+	fn deref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl<const L: usize> fmt::Display for ConstSqid<L> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl<const L: usize> fmt::Debug for ConstSqid<L> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(self.as_str(), f)
+	}
+}
+
+/// Deterministically shuffle `chars`, between the numbers of a sqid and to pad it.
+pub(crate) const fn shuffle(chars: &mut [char]) {
+	let len = chars.len();
+	let mut i = 0;
+	while i < len - 1 {
+		let j = len - 1 - i;
+		let r = (i as u32 * j as u32 + chars[i] as u32 + chars[j] as u32) % len as u32;
+		chars.swap(i, r as usize);
+		i += 1;
+	}
+}
+
+/// The term of the `i`th number of a sqid in its offset, which is the sum of the terms and the
+/// count of numbers, modulo the length of the alphabet.
+pub(crate) const fn offset_term(alphabet: &[char], i: usize, number: u64) -> usize {
+	alphabet[(number % alphabet.len() as u64) as usize] as usize + i
+}
+
+/// Write the digits of `number` in base `alphabet` at the end of `digits`, returning the position
+/// of the first one. A `u64` has at most 64 digits since an alphabet has at least 2 characters.
+pub(crate) const fn to_digits(number: u64, alphabet: &[char], digits: &mut [char; 64]) -> usize {
+	let base = alphabet.len() as u64;
+	let mut result = number;
+	let mut start = digits.len();
+
+	loop {
+		start -= 1;
+		digits[start] = alphabet[(result % base) as usize];
+		result /= base;
+
+		if result == 0 {
+			return start;
+		}
+	}
+}
+
+/// Whether the blocklisted `word` blocks `id`, ignoring case.
+pub(crate) const fn is_blocked_by(id: &[u8], word: &[u8]) -> bool {
+	if word.len() > id.len() {
+		return false;
+	}
+
+	let mut has_digit = false;
+	let mut i = 0;
+	while i < word.len() {
+		has_digit |= word[i].is_ascii_digit();
+		i += 1;
+	}
+
+	if id.len() <= 3 || word.len() <= 3 {
+		id.len() == word.len() && matches_at(id, word, 0)
+	} else if has_digit {
+		matches_at(id, word, 0) || matches_at(id, word, id.len() - word.len())
+	} else {
+		let mut start = 0;
+		while start + word.len() <= id.len() {
+			if matches_at(id, word, start) {
+				return true;
+			}
+			start += 1;
+		}
+		false
+	}
+}
+
+const fn matches_at(id: &[u8], word: &[u8], start: usize) -> bool {
+	let mut i = 0;
+	while i < word.len() {
+		if !id[start + i].eq_ignore_ascii_case(&word[i]) {
+			return false;
+		}
+		i += 1;
+	}
+
+	true
+}
+
+/// The count of words of a blocklist in JSON, a flat array of strings without escapes.
+const fn blocklist_len(json: &str) -> usize {
+	let json = json.as_bytes();
+	let mut quotes = 0;
+	let mut i = 0;
+	while i < json.len() {
+		if json[i] == b'"' {
+			quotes += 1;
+		}
+		i += 1;
+	}
+
+	quotes / 2
+}
+
+/// The words of a blocklist in JSON, see [blocklist_len].
+const fn parse_blocklist<const W: usize>(json: &'static str) -> [&'static str; W] {
+	let mut words = [""; W];
+	let mut rest = json;
+	let mut i = 0;
+	while i < W {
+		let (_, word) = split_once_quote(rest);
+		let (word, after) = split_once_quote(word);
+		words[i] = word;
+		rest = after;
+		i += 1;
+	}
+
+	words
+}
+
+/// Split `s` around its first `"`.
+const fn split_once_quote(s: &'static str) -> (&'static str, &'static str) {
+	let bytes = s.as_bytes();
+	let mut i = 0;
+	while bytes[i] != b'"' {
+		i += 1;
+	}
+
+	let (before, after) = s.split_at(i);
+	(before, after.split_at(1).1)
+}
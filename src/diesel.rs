@@ -0,0 +1,42 @@
+//! [Diesel](https://docs.rs/diesel) support for typed IDs.
+//!
+//! A [Sqid](struct@Sqid) can be used as a field of `Queryable` and `Insertable` structs for a
+//! `BigInt` column, see [Integrations](struct@Sqid#integrations) for how it is stored.
+//!
+//! `Numeric` columns are not supported: diesel maps them to `bigdecimal::BigDecimal` behind its
+//! own `numeric` feature, and a `BigInt` already holds every ID a sequence or `BIGSERIAL` hands
+//! out. Numbers above `i64::MAX` fail to bind instead of wrapping into negative IDs.
+
+use ::diesel::{
+	backend::Backend,
+	deserialize::{self, FromSql},
+	serialize::{self, Output, ToSql},
+	sql_types::BigInt,
+};
+
+use crate::Sqid;
+
+impl<T, DB> ToSql<BigInt, DB> for Sqid<T>
+where
+	DB: Backend,
+	i64: ToSql<BigInt, DB>,
+{
+	fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+		match (self.numbers(), &self.big_int) {
+			(_, Some(number)) => number.to_sql(out),
+			([_], None) => Err(format!("sqid {self:?} does not fit in a BigInt").into()),
+			_ => Err(format!("sqid {self:?} is not a single number").into()),
+		}
+	}
+}
+
+impl<T, DB> FromSql<BigInt, DB> for Sqid<T>
+where
+	DB: Backend,
+	i64: FromSql<BigInt, DB>,
+{
+	fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+		let number = i64::from_sql(bytes)?;
+		Ok(Sqid::from(u64::try_from(number)?))
+	}
+}
@@ -10,13 +10,74 @@
 /// **Note**: This is the crate's license and not an actual item.
 pub const LICENSE: () = ();
 
-use std::{cmp::min, collections::HashSet, result};
-
+#[cfg(feature = "actix")]
+pub mod actix;
+mod alphabet;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "rayon")]
+mod batch;
+mod constant;
+#[cfg(feature = "diesel")]
+mod diesel;
+mod numbers;
+mod prefixed;
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "rocket")]
+pub mod rocket;
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+pub mod serde;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+mod typed;
+
+use std::{
+	borrow::Cow,
+	cmp::min,
+	collections::{HashMap, HashSet},
+	iter, result,
+};
+
+#[cfg(feature = "rand")]
+pub use alphabet::random_alphabet;
+pub use alphabet::Alphabet;
+pub use constant::{ConstSqid, ConstSqids};
 use derive_builder::Builder;
+pub use numbers::{FromSqidNumbers, ToSqidNumbers};
+pub use prefixed::PrefixedSqids;
+#[cfg(feature = "registry")]
+pub use registry::{get, register};
+#[cfg(feature = "derive")]
+pub use sqids_derive::Sqid;
 use thiserror::Error;
+pub use typed::{Sqid, SqidsConfig};
+
+// Used by the code generated by `sqids-derive`, not public API.
+#[doc(hidden)]
+pub mod __private {
+	pub use ::serde;
+
+	use crate::{Error, Result, Sqids};
+
+	/// Check that `prefix` ends with a delimiter that is not in the alphabet of `sqids`, for
+	/// prefixes of registered [Sqids] whose alphabet is only known at runtime.
+	pub fn check_prefix<'a>(sqids: &'a Sqids, prefix: &str) -> Result<&'a Sqids> {
+		match prefix.chars().last() {
+			Some(delimiter) if !sqids.alphabet.contains(&delimiter) => Ok(sqids),
+			_ => Err(Error::PrefixDelimiter),
+		}
+	}
+}
 
 /// sqids Error type.
+///
+/// New variants can be added along with new options, so matches on it need a wildcard arm.
 #[derive(Error, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
 	/// Alphabet cannot contain multibyte characters
 	///
@@ -45,6 +106,172 @@ pub enum Error {
 	/// ```
 	#[error("Alphabet must contain unique characters")]
 	AlphabetUniqueCharacters,
+	/// Alphabet cannot contain characters that differ only by case when decoding is
+	/// case-insensitive
+	///
+	/// ```
+	/// # use sqids::{Sqids, Error};
+	/// let error = Sqids::builder().case_insensitive(true).build().unwrap_err();
+	/// assert_eq!(error, Error::AlphabetCaseCollision);
+	/// ```
+	#[error("Alphabet cannot contain characters that differ only by case when decoding is case-insensitive")]
+	AlphabetCaseCollision,
+	/// Confusable characters must map to a character in the alphabet
+	///
+	/// ```
+	/// # use sqids::{Sqids, Error};
+	/// let error = Sqids::builder().confusables(&[('!', '?')]).build().unwrap_err();
+	/// assert_eq!(error, Error::ConfusableTarget);
+	/// ```
+	#[error("Confusable characters must map to a character in the alphabet")]
+	ConfusableTarget,
+	/// Confusable characters cannot be in the alphabet, in either letter case when case-insensitive
+	///
+	/// ```
+	/// # use sqids::{Alphabet, Sqids, Error};
+	/// let error = Sqids::builder().confusables(&[('O', '0')]).build().unwrap_err();
+	/// assert_eq!(error, Error::ConfusableSource);
+	///
+	/// let error = Sqids::builder()
+	/// 	.alphabet(Alphabet::Lowercase.into())
+	/// 	.case_insensitive(true)
+	/// 	.confusables(&[('O', '0')])
+	/// 	.build()
+	/// 	.unwrap_err();
+	/// assert_eq!(error, Error::ConfusableSource);
+	/// ```
+	#[error("Confusable characters cannot be in the alphabet")]
+	ConfusableSource,
+	/// The ID cannot be decoded
+	///
+	/// ```
+	/// # use sqids::{Sqids, Error};
+	/// let error = Sqids::default().try_decode("*").unwrap_err();
+	/// assert_eq!(error, Error::InvalidId);
+	/// ```
+	#[error("The ID cannot be decoded")]
+	InvalidId,
+	/// The check character of the ID does not match, the ID was most likely mistyped
+	///
+	/// ```
+	/// # use sqids::{Sqids, Error};
+	/// let sqids = Sqids::builder().check_character(true).build().unwrap();
+	/// let id = sqids.encode(&[1, 2, 3]).unwrap();
+	/// let mistyped = format!("{}{}", if id.starts_with('a') { 'b' } else { 'a' }, &id[1..]);
+	/// let error = sqids.try_decode(&mistyped).unwrap_err();
+	/// assert_eq!(error, Error::CheckCharacterMismatch);
+	/// ```
+	#[error("The check character of the ID does not match")]
+	CheckCharacterMismatch,
+	/// The ID decodes into numbers that are encoded into a different ID
+	///
+	/// ```
+	/// # use sqids::{Sqids, Error};
+	/// let error = Sqids::default().decode_canonical("se8ojk").unwrap_err();
+	/// assert_eq!(error, Error::NonCanonicalId);
+	/// ```
+	#[error("The ID decodes into numbers that are encoded into a different ID")]
+	NonCanonicalId,
+	/// The ID decodes into a different count of numbers than the value has
+	///
+	/// ```
+	/// # use sqids::{Sqids, Error};
+	/// let sqids = Sqids::default();
+	/// let error = sqids.decode_value::<(u64, u64)>("86Rf07").unwrap_err();
+	/// assert_eq!(error, Error::Arity { expected: 2, actual: 3 });
+	/// ```
+	#[error("Expected an ID of {expected} numbers, got {actual}")]
+	Arity {
+		/// Count of numbers of the value
+		expected: usize,
+		/// Count of numbers decoded from the ID
+		actual: usize,
+	},
+	/// Group size must be at least 1
+	///
+	/// ```
+	/// # use sqids::{Sqids, Error};
+	/// let error = Sqids::builder().group(0, '-').build().unwrap_err();
+	/// assert_eq!(error, Error::GroupSize);
+	/// ```
+	#[error("Group size must be at least 1")]
+	GroupSize,
+	/// Group separator cannot be in the alphabet
+	///
+	/// ```
+	/// # use sqids::{Sqids, Error};
+	/// let error = Sqids::builder().group(4, 'a').build().unwrap_err();
+	/// assert_eq!(error, Error::GroupSeparator);
+	/// ```
+	#[error("Group separator cannot be in the alphabet")]
+	GroupSeparator,
+	/// Prefix must end with a delimiter that is not in the alphabet
+	///
+	/// ```
+	/// # use sqids::{Error, PrefixedSqids, Sqids};
+	/// let mut sqids = PrefixedSqids::new(Sqids::default());
+	/// assert_eq!(sqids.register("user", "usr"), Err(Error::PrefixDelimiter));
+	/// ```
+	#[error("Prefix must end with a delimiter that is not in the alphabet")]
+	PrefixDelimiter,
+	/// Prefix or entity is already registered
+	///
+	/// ```
+	/// # use sqids::{Error, PrefixedSqids, Sqids};
+	/// let mut sqids = PrefixedSqids::new(Sqids::default());
+	/// sqids.register("user", "u_").unwrap();
+	/// assert_eq!(sqids.register("admin", "u_a_"), Err(Error::PrefixAmbiguous));
+	/// ```
+	#[error("Prefix or entity is already registered")]
+	PrefixAmbiguous,
+	/// No prefix is registered for the entity or the ID
+	///
+	/// ```
+	/// # use sqids::{Error, PrefixedSqids, Sqids};
+	/// let sqids = PrefixedSqids::<&str>::new(Sqids::default());
+	/// assert_eq!(sqids.decode("usr_86Rf07"), Err(Error::PrefixUnknown));
+	/// ```
+	#[error("No prefix is registered for the entity or the ID")]
+	PrefixUnknown,
+	/// A [Sqids] is already registered
+	///
+	/// ```
+	/// # use sqids::{Sqids, Error};
+	/// sqids::serde::set_global(Sqids::default()).unwrap();
+	/// let error = sqids::serde::set_global(Sqids::default()).unwrap_err();
+	/// assert_eq!(error, Error::AlreadyRegistered);
+	/// ```
+	#[error("A Sqids is already registered")]
+	AlreadyRegistered,
+	/// The registry is frozen and no more Sqids can be registered
+	///
+	/// ```
+	/// # #[cfg(feature = "registry")]
+	/// # {
+	/// # use sqids::{Sqids, Error};
+	/// sqids::registry::freeze();
+	/// let error = sqids::register("orders", Sqids::default()).unwrap_err();
+	/// assert_eq!(error, Error::RegistryFrozen);
+	/// # }
+	/// ```
+	#[error("The registry is frozen")]
+	RegistryFrozen,
+	/// No [Sqids] is registered under the name
+	///
+	/// ```
+	/// # #[cfg(all(feature = "derive", feature = "registry"))]
+	/// # {
+	/// # use sqids::{Error, Sqid};
+	/// #[derive(Debug, Sqid)]
+	/// #[sqid(registry = "invoices")]
+	/// struct InvoiceId(u64);
+	///
+	/// let error = "Uk".parse::<InvoiceId>().unwrap_err();
+	/// assert_eq!(error, Error::NotRegistered);
+	/// # }
+	/// ```
+	#[error("No Sqids is registered under the name")]
+	NotRegistered,
 	/// Reached max attempts to re-generate the ID
 	///
 	/// ```
@@ -68,6 +295,21 @@ pub type Result<T> = result::Result<T, Error>;
 /// The default alphabet used when none is given when creating a [Sqids].
 pub const DEFAULT_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
 
+/// [DEFAULT_ALPHABET] as a byte array, for creating a [ConstSqids].
+pub const DEFAULT_ALPHABET_BYTES: &[u8; 62] = match DEFAULT_ALPHABET.as_bytes().first_chunk() {
+	Some(bytes) => bytes,
+	None => unreachable!(),
+};
+
+/// The most typos [Sqids::suggest_corrections] looks for.
+pub const MAX_SUGGESTION_EDITS: usize = 2;
+
+/// The most suggestions [Sqids::suggest_corrections] returns.
+pub const MAX_SUGGESTIONS: usize = 10;
+
+/// The longest sqid, in characters, [Sqids::suggest_corrections] looks for typos in.
+pub const MAX_SUGGESTION_ID_LEN: usize = 10;
+
 /// Returns the default blocklist when none is given when creating a [Sqids].
 pub fn default_blocklist() -> HashSet<String> {
 	serde_json::from_str(include_str!("blocklist.json")).unwrap()
@@ -129,6 +371,19 @@ pub struct Sqids {
 	/// Blocklist. When creating a sqid strings that begins
 	/// with one of these will be avoided.
 	blocklist: HashSet<String>,
+	/// Whether [Sqids::decode] accepts sqids regardless of letter case. Requires an alphabet
+	/// without characters that differ only by case.
+	case_insensitive: bool,
+	/// Characters outside of the alphabet that [Sqids::decode] replaces with an alphabet
+	/// character before decoding.
+	#[builder(setter(custom))]
+	confusables: HashMap<char, char>,
+	/// Whether [Sqids::encode] appends a check character that [Sqids::decode] verifies, so that
+	/// mistyped sqids are rejected instead of decoding into different numbers.
+	check_character: bool,
+	/// Size and separator of the character groups [Sqids::encode] splits sqids into.
+	#[builder(setter(custom))]
+	group: Option<(usize, char)>,
 }
 
 impl Default for Sqids {
@@ -143,6 +398,77 @@ impl SqidsBuilder {
 		Self::default()
 	}
 
+	/// Derive the alphabet by deterministically permuting it with a secret `seed`.
+	///
+	/// The alphabet set with [SqidsBuilder::alphabet] before this call is used as the base,
+	/// otherwise [DEFAULT_ALPHABET] is. The same base and seed always produce the same alphabet,
+	/// so rotating the seed is enough to get a new set of IDs.
+	///
+	/// The algorithm is intentionally simple so it can be reproduced in other languages:
+	///
+	/// 1. The seed is hashed into a 64-bit state with FNV-1a (offset basis `0xcbf29ce484222325`,
+	///    prime `0x100000001b3`).
+	/// 2. The alphabet is shuffled with a Fisher-Yates pass from the last index down to `1`, where
+	///    the swap index for position `i` is `splitmix64(state) % (i + 1)`. Each splitmix64 call
+	///    adds `0x9e3779b97f4a7c15` to the state and mixes the new state with the multipliers
+	///    `0xbf58476d1ce4e5b9` and `0x94d049bb133111eb`, as in the reference implementation.
+	///
+	/// This is not a cryptographic construction; it only saves users from hand-shuffling
+	/// alphabets, and anyone with enough IDs can recover the alphabet.
+	///
+	/// ```
+	/// # use sqids::Sqids;
+	/// let sqids = Sqids::builder().alphabet_from_seed(b"my secret").build()?;
+	/// let id = sqids.encode(&[1, 2, 3])?;
+	/// assert_eq!(sqids.decode(&id), [1, 2, 3]);
+	/// # Ok::<(), sqids::Error>(())
+	/// ```
+	pub fn alphabet_from_seed(mut self, seed: &[u8]) -> Self {
+		let base = self.alphabet.take().unwrap_or_else(|| DEFAULT_ALPHABET.chars().collect());
+		self.alphabet = Some(alphabet::permute_from_seed(&base, seed));
+		self
+	}
+
+	/// Map characters that are commonly mistyped for alphabet characters, such as `O` for `0`.
+	///
+	/// [Sqids::decode] replaces each source character with its target before decoding. Sources
+	/// must not be in the alphabet, in either letter case when [SqidsBuilder::case_insensitive] is
+	/// enabled, and targets must be.
+	///
+	/// ```
+	/// # use sqids::{Alphabet, Sqids};
+	/// let sqids = Sqids::builder()
+	/// 	.alphabet(Alphabet::Crockford32.into())
+	/// 	.confusables(&[('O', '0'), ('I', '1'), ('L', '1')])
+	/// 	.build()?;
+	/// let id = sqids.encode(&[1, 2, 3])?;
+	/// assert_eq!(sqids.decode(&id.replace('0', "O").replace('1', "I")), [1, 2, 3]);
+	/// # Ok::<(), sqids::Error>(())
+	/// ```
+	pub fn confusables(mut self, confusables: &[(char, char)]) -> Self {
+		self.confusables = Some(confusables.iter().copied().collect());
+		self
+	}
+
+	/// Split generated sqids into groups of `size` characters joined by `separator`, for example
+	/// `86Rf-07`.
+	///
+	/// [Sqids::decode] ignores the separator and whitespace, so grouped and ungrouped sqids decode
+	/// the same. The separator must not be in the alphabet.
+	///
+	/// ```
+	/// # use sqids::Sqids;
+	/// let sqids = Sqids::builder().group(4, '-').build()?;
+	/// assert_eq!(sqids.encode(&[1, 2, 3])?, "86Rf-07");
+	/// assert_eq!(sqids.decode("86Rf-07"), [1, 2, 3]);
+	/// assert_eq!(sqids.decode("86Rf07"), [1, 2, 3]);
+	/// # Ok::<(), sqids::Error>(())
+	/// ```
+	pub fn group(mut self, size: usize, separator: char) -> Self {
+		self.group = Some(Some((size, separator)));
+		self
+	}
+
 	/// Build a [Sqids] object.
 	pub fn build(self) -> Result<Sqids> {
 		let alphabet: Vec<char> =
@@ -163,6 +489,40 @@ impl SqidsBuilder {
 			return Err(Error::AlphabetUniqueCharacters);
 		}
 
+		let case_insensitive = self.case_insensitive.unwrap_or(false);
+		if case_insensitive {
+			let folded_chars: HashSet<char> =
+				alphabet.iter().map(|c| c.to_ascii_lowercase()).collect();
+			if folded_chars.len() != alphabet.len() {
+				return Err(Error::AlphabetCaseCollision);
+			}
+		}
+
+		let confusables = self.confusables.unwrap_or_default();
+		for (source, target) in confusables.iter() {
+			// case folding runs before the confusables, so a source that folds into the alphabet
+			// would never be replaced
+			let source_folds = case_insensitive
+				&& (alphabet.contains(&source.to_ascii_lowercase())
+					|| alphabet.contains(&source.to_ascii_uppercase()));
+			if alphabet.contains(source) || source_folds {
+				return Err(Error::ConfusableSource);
+			}
+			if !alphabet.contains(target) {
+				return Err(Error::ConfusableTarget);
+			}
+		}
+
+		let group = self.group.flatten();
+		if let Some((size, separator)) = group {
+			if size == 0 {
+				return Err(Error::GroupSize);
+			}
+			if alphabet.contains(&separator) {
+				return Err(Error::GroupSeparator);
+			}
+		}
+
 		let lowercase_alphabet: Vec<char> =
 			alphabet.iter().map(|c| c.to_ascii_lowercase()).collect();
 		let filtered_blocklist: HashSet<String> = self
@@ -183,6 +543,10 @@ impl SqidsBuilder {
 			alphabet: Sqids::shuffle(&alphabet),
 			min_length: self.min_length.unwrap_or(0),
 			blocklist: filtered_blocklist,
+			case_insensitive,
+			confusables,
+			check_character: self.check_character.unwrap_or(false),
+			group,
 		})
 	}
 }
@@ -210,72 +574,440 @@ impl Sqids {
 	/// When the alphabet is exhausted and all possible sqids for this input are blocked
 	/// [Error::BlocklistMaxAttempts] is returned.
 	pub fn encode(&self, numbers: &[u64]) -> Result<String> {
-		if numbers.is_empty() {
+		self.encode_iter(numbers.iter().copied())
+	}
+
+	/// Generate a sqid from an iterator of numbers, without collecting them first.
+	///
+	/// The numbers are read once to compute the shuffle offset, and again for each attempt to
+	/// generate the sqid (see [Sqids::encode]). So the iterator has to know its length and be
+	/// cheap to clone, like iterators over arrays and slices and their adapters. Other iterators
+	/// can be collected into a `Vec` first.
+	///
+	/// ```
+	/// # use sqids::Sqids;
+	/// struct Row {
+	/// 	tenant_id: u64,
+	/// 	item_id: u64,
+	/// }
+	///
+	/// let sqids = Sqids::default();
+	/// let row = Row { tenant_id: 1, item_id: 2 };
+	/// let id = sqids.encode_iter([row.tenant_id, row.item_id])?;
+	/// assert_eq!(id, sqids.encode(&[1, 2])?);
+	///
+	/// let ids = ["1", "2", "3"];
+	/// let id = sqids.encode_iter(ids.iter().map(|id| id.parse::<u64>().unwrap()))?;
+	/// assert_eq!(id, "86Rf07");
+	/// # Ok::<(), sqids::Error>(())
+	/// ```
+	pub fn encode_iter<I>(&self, numbers: I) -> Result<String>
+	where
+		I: IntoIterator<Item = u64>,
+		I::IntoIter: ExactSizeIterator + Clone,
+	{
+		let numbers = numbers.into_iter();
+		if numbers.len() == 0 {
 			return Ok(String::new());
 		}
 
-		self.encode_numbers(numbers, 0)
+		self.encode_numbers(numbers, 0).map(|id| self.format(id))
+	}
+
+	/// Generate a sqid from the numbers of a value, see [ToSqidNumbers].
+	///
+	/// ```
+	/// # use sqids::Sqids;
+	/// let sqids = Sqids::default();
+	/// assert_eq!(sqids.encode_value(&(1, 2, 3))?, "86Rf07");
+	/// assert_eq!(sqids.encode_value(&[1, 2, 3])?, "86Rf07");
+	/// # Ok::<(), sqids::Error>(())
+	/// ```
+	pub fn encode_value<T: ToSqidNumbers + ?Sized>(&self, value: &T) -> Result<String> {
+		self.encode_iter(value.to_sqid_numbers())
 	}
 
 	/// Decode a sqid into a vector of numbers. When an invalid sqid is encountered an empty vector
 	/// is returned.
+	///
+	/// When [SqidsBuilder::case_insensitive] is enabled, letters are matched against the alphabet
+	/// regardless of their case. Characters registered with [SqidsBuilder::confusables] are
+	/// replaced with their alphabet counterparts. When [SqidsBuilder::group] is set, separators
+	/// and whitespace are ignored.
 	pub fn decode(&self, id: &str) -> Vec<u64> {
-		let mut ret = Vec::new();
+		self.try_decode(id).unwrap_or_default()
+	}
+
+	/// Decode a sqid into a vector of numbers, reporting why an invalid sqid was rejected.
+	///
+	/// Returns [Error::CheckCharacterMismatch] when [SqidsBuilder::check_character] is enabled
+	/// and the sqid was mistyped, and [Error::InvalidId] for any other sqid that cannot be
+	/// decoded.
+	///
+	/// ```
+	/// # use sqids::{Error, Sqids};
+	/// let sqids = Sqids::builder().check_character(true).build()?;
+	/// let id = sqids.encode(&[1, 2, 3])?;
+	/// assert_eq!(sqids.try_decode(&id), Ok(vec![1, 2, 3]));
+	/// assert_eq!(sqids.try_decode("!"), Err(Error::InvalidId));
+	/// # Ok::<(), sqids::Error>(())
+	/// ```
+	pub fn try_decode(&self, id: &str) -> Result<Vec<u64>> {
+		self.try_decode_with(id, &self.alphabet_index())
+	}
 
+	fn try_decode_with(&self, id: &str, alphabet_index: &AlphabetIndex) -> Result<Vec<u64>> {
+		let id = self.validate(id, alphabet_index)?;
 		if id.is_empty() {
-			return ret;
+			return Ok(Vec::new());
 		}
 
-		let alphabet_chars: HashSet<char> = self.alphabet.iter().cloned().collect();
-		if !id.chars().all(|c| alphabet_chars.contains(&c)) {
-			return ret;
+		let numbers: Vec<u64> = Numbers::new(self, id).collect();
+		if numbers.is_empty() {
+			return Err(Error::InvalidId);
 		}
 
-		let prefix = id.chars().next().unwrap();
-		let offset = self.alphabet.iter().position(|&c| c == prefix).unwrap();
-		let mut alphabet: Vec<char> =
-			self.alphabet.iter().cycle().skip(offset).take(self.alphabet.len()).copied().collect();
+		Ok(numbers)
+	}
 
-		alphabet = alphabet.into_iter().rev().collect();
+	/// Decode a sqid like [Sqids::try_decode], yielding its numbers one at a time instead of
+	/// collecting them into a `Vec`.
+	///
+	/// An invalid sqid yields a single error. The sqid is validated before the first number is
+	/// yielded, except for a number that overflows a `u64`, which is skipped like in
+	/// [Sqids::decode].
+	///
+	/// ```
+	/// # use sqids::{Error, Sqids};
+	/// let sqids = Sqids::default();
+	///
+	/// let total = sqids.decode_iter("86Rf07").sum::<Result<u64, _>>()?;
+	/// assert_eq!(total, 6);
+	///
+	/// let mut numbers = sqids.decode_iter("86Rf07!");
+	/// assert_eq!(numbers.next(), Some(Err(Error::InvalidId)));
+	/// assert_eq!(numbers.next(), None);
+	/// # Ok::<(), sqids::Error>(())
+	/// ```
+	pub fn decode_iter<'a>(&'a self, id: &'a str) -> impl Iterator<Item = Result<u64>> + 'a {
+		let (mut numbers, mut error) = match self.validate(id, &self.alphabet_index()) {
+			Ok(id) if id.is_empty() => (None, None),
+			Ok(id) => (Some(Numbers::new(self, id)), None),
+			Err(error) => (None, Some(error)),
+		};
+		let mut decoded_any = false;
+
+		iter::from_fn(move || {
+			if let Some(error) = error.take() {
+				return Some(Err(error));
+			}
 
-		let mut id = id[1..].to_string();
+			match numbers.as_mut()?.next() {
+				Some(number) => {
+					decoded_any = true;
+					Some(Ok(number))
+				}
+				None => {
+					numbers = None;
+					(!decoded_any).then_some(Err(Error::InvalidId))
+				}
+			}
+		})
+	}
 
-		while !id.is_empty() {
-			let separator = alphabet[0];
+	/// Decode a sqid like [Sqids::try_decode], but only accept the sqid that [Sqids::encode]
+	/// generates for the decoded numbers.
+	///
+	/// Because of the algorithm's design multiple sqids can decode into the same numbers.
+	/// Rejecting the others with [Error::NonCanonicalId] guarantees that each list of numbers
+	/// has exactly one valid sqid.
+	///
+	/// ```
+	/// # use sqids::{Error, Sqids};
+	/// let sqids = Sqids::default();
+	/// assert_eq!(sqids.decode_canonical("86Rf07"), Ok(vec![1, 2, 3]));
+	/// assert_eq!(sqids.decode("se8ojk"), [1, 2, 3]);
+	/// assert_eq!(sqids.decode_canonical("se8ojk"), Err(Error::NonCanonicalId));
+	/// # Ok::<(), sqids::Error>(())
+	/// ```
+	pub fn decode_canonical(&self, id: &str) -> Result<Vec<u64>> {
+		self.decode_canonical_with(id, &self.alphabet_index())
+	}
+
+	fn decode_canonical_with(&self, id: &str, alphabet_index: &AlphabetIndex) -> Result<Vec<u64>> {
+		let numbers = self.try_decode_with(id, alphabet_index)?;
+
+		if !numbers.is_empty() && !self.is_canonical(&self.normalize(id), &numbers) {
+			return Err(Error::NonCanonicalId);
+		}
+
+		Ok(numbers)
+	}
+
+	/// Decode a canonical sqid (see [Sqids::decode_canonical]) into a value, see
+	/// [FromSqidNumbers].
+	///
+	/// Returns [Error::Arity] when the sqid has a different count of numbers than the value, and
+	/// [Error::InvalidId] for an empty sqid.
+	///
+	/// ```
+	/// # use sqids::{Error, Sqids};
+	/// let sqids = Sqids::default();
+	/// assert_eq!(sqids.decode_value::<(u64, u64, u64)>("86Rf07"), Ok((1, 2, 3)));
+	/// assert_eq!(sqids.decode_value::<u64>("86Rf07"), Err(Error::Arity { expected: 1, actual: 3 }));
+	/// # Ok::<(), sqids::Error>(())
+	/// ```
+	pub fn decode_value<T: FromSqidNumbers>(&self, id: &str) -> Result<T> {
+		let numbers = self.decode_canonical(id)?;
+		if numbers.is_empty() {
+			return Err(Error::InvalidId);
+		}
+
+		T::from_sqid_numbers(numbers)
+	}
 
-			let chunks: Vec<&str> = id.split(separator).collect();
-			if !chunks.is_empty() {
-				if chunks[0].is_empty() {
-					return ret;
+	/// Suggest sqids within `max_edits` typos of `id`, for recovering IDs that fail to decode.
+	///
+	/// A typo is a substituted or transposed character, and each suggestion has at most one
+	/// inserted or deleted character. Only suggestions that decode to numbers which encode back
+	/// into the same sqid are returned, at most [MAX_SUGGESTIONS] of them, ordered by the number
+	/// of typos and, for a single typo, alphabetically. The number of candidates grows very quickly with
+	/// both, so `max_edits` above [MAX_SUGGESTION_EDITS] or sqids longer than
+	/// [MAX_SUGGESTION_ID_LEN] characters return no suggestions.
+	///
+	/// ```
+	/// # use sqids::Sqids;
+	/// let sqids = Sqids::builder().check_character(true).build()?;
+	/// let id = sqids.encode(&[1, 2, 3])?;
+	/// let mistyped: String = id.chars().take(id.len() - 1).collect();
+	/// assert!(sqids.suggest_corrections(&mistyped, 1).contains(&id));
+	/// # Ok::<(), sqids::Error>(())
+	/// ```
+	pub fn suggest_corrections(&self, id: &str, max_edits: usize) -> Vec<String> {
+		let id: Vec<char> = self.normalize(id).chars().collect();
+		if max_edits == 0 || max_edits > MAX_SUGGESTION_EDITS || id.len() > MAX_SUGGESTION_ID_LEN {
+			return Vec::new();
+		}
+
+		// each typo fixes at most one character that is not in the alphabet
+		let alphabet_index = self.alphabet_index();
+		let unknown_chars = |candidate: &[char]| {
+			candidate.iter().filter(|&&c| position(&alphabet_index, c).is_none()).count()
+		};
+		if unknown_chars(&id) > max_edits {
+			return Vec::new();
+		}
+
+		let is_suggestion = |candidate: &str| {
+			self.decode_canonical_with(candidate, &alphabet_index)
+				.is_ok_and(|numbers| !numbers.is_empty())
+		};
+
+		let mut one_edit: Vec<(String, bool)> = self.edits(&id, false).collect();
+		// of duplicates, the candidate that was not resized sorts first and is kept, as it can
+		// still be resized by a second edit
+		one_edit.sort_unstable();
+		one_edit.dedup_by(|a, b| a.0 == b.0);
+
+		// testing the candidates in order allows stopping as soon as there are enough suggestions
+		let mut suggestions: Vec<String> = one_edit
+			.iter()
+			.map(|(candidate, _)| candidate)
+			.filter(|candidate| is_suggestion(candidate))
+			.take(MAX_SUGGESTIONS)
+			.cloned()
+			.collect();
+
+		if max_edits > 1 {
+			// the second edits are far too many to collect and sort, so they are generated from
+			// the sorted first edits and tested one by one until there are enough suggestions
+			let id: String = id.iter().collect();
+			let seen: HashSet<&str> = one_edit
+				.iter()
+				.map(|(candidate, _)| candidate.as_str())
+				.chain([id.as_str()])
+				.collect();
+
+			'candidates: for (candidate, resized) in &one_edit {
+				let candidate: Vec<char> = candidate.chars().collect();
+				if unknown_chars(&candidate) > 1 {
+					continue;
 				}
 
-				let alphabet_without_separator: Vec<char> =
-					alphabet.iter().copied().skip(1).collect();
-				if let Some(value) = self.to_number(chunks[0], &alphabet_without_separator) {
-					ret.push(value)
+				for (edit, _) in self.edits(&candidate, *resized) {
+					if suggestions.len() == MAX_SUGGESTIONS {
+						break 'candidates;
+					}
+					if is_suggestion(&edit)
+						&& !seen.contains(edit.as_str())
+						&& !suggestions.contains(&edit)
+					{
+						suggestions.push(edit);
+					}
 				}
+			}
+		}
 
-				if chunks.len() > 1 {
-					alphabet = Self::shuffle(&alphabet);
+		suggestions.into_iter().map(|id| self.format(id)).collect()
+	}
+
+	/// The candidates one typo away from `id`, and whether they were inserted into or deleted
+	/// from, which `resized` tells for `id`.
+	fn edits<'a>(
+		&'a self,
+		id: &'a [char],
+		resized: bool,
+	) -> impl Iterator<Item = (String, bool)> + 'a {
+		let splice = move |at: usize, removed: usize, inserted: &[char]| -> String {
+			id[..at].iter().chain(inserted).chain(&id[at + removed..]).collect()
+		};
+
+		let substitutions = (0..id.len()).flat_map(move |i| {
+			self.alphabet
+				.iter()
+				.filter(move |&&c| c != id[i])
+				.map(move |&c| (splice(i, 1, &[c]), resized))
+		});
+		let transpositions = (0..id.len().saturating_sub(1))
+			.filter(move |&i| id[i] != id[i + 1])
+			.map(move |i| (splice(i, 2, &[id[i + 1], id[i]]), resized));
+		let deletions = (0..id.len())
+			.filter(move |_| !resized && id.len() > 1)
+			.map(move |i| (splice(i, 1, &[]), true));
+		let insertions = (0..=id.len())
+			.filter(move |_| !resized)
+			.flat_map(move |i| self.alphabet.iter().map(move |&c| (splice(i, 0, &[c]), true)));
+
+		substitutions.chain(transpositions).chain(deletions).chain(insertions)
+	}
+
+	fn is_canonical(&self, id: &str, numbers: &[u64]) -> bool {
+		// the prefix tells which blocklist attempt produced the sqid, which is only canonical if
+		// all attempts before it were blocked
+		let prefix = self.alphabet.iter().position(|&c| id.starts_with(c)).unwrap();
+		let numbers = numbers.iter().copied();
+		let increment =
+			(prefix + self.alphabet.len() - self.offset(numbers.clone())) % self.alphabet.len();
+
+		self.encode_attempt(numbers.clone(), increment) == id
+			&& !self.is_blocked_id(id)
+			&& (0..increment).all(|i| self.is_blocked_id(&self.encode_attempt(numbers.clone(), i)))
+	}
+
+	/// Normalize `id` and check that it is made of alphabet characters, with a valid check
+	/// character which is removed.
+	fn validate<'a>(&self, id: &'a str, alphabet_index: &AlphabetIndex) -> Result<Cow<'a, str>> {
+		let mut id = self.normalize(id);
+
+		if id.is_empty() {
+			return Ok(id);
+		}
+
+		if !id.chars().all(|c| position(alphabet_index, c).is_some()) {
+			return Err(Error::InvalidId);
+		}
+
+		if self.check_character {
+			let (body, check) = id.split_at(id.len() - 1);
+			if body.is_empty() {
+				return Err(Error::InvalidId);
+			}
+			if !check.starts_with(self.check_character_for(body, alphabet_index)) {
+				return Err(Error::CheckCharacterMismatch);
+			}
+
+			id = match id {
+				Cow::Borrowed(id) => Cow::Borrowed(&id[..id.len() - 1]),
+				Cow::Owned(mut id) => {
+					id.pop();
+					Cow::Owned(id)
 				}
+			};
+		}
+
+		Ok(id)
+	}
+
+	fn alphabet_index(&self) -> AlphabetIndex {
+		let mut index = [None; 128];
+		for (i, &c) in self.alphabet.iter().enumerate() {
+			index[c as usize] = Some(i as u8);
+		}
+		index
+	}
+
+	fn format(&self, id: String) -> String {
+		let Some((size, separator)) = self.group else {
+			return id;
+		};
+
+		let mut formatted = String::with_capacity(id.len() + id.len() / size);
+		for (i, c) in id.chars().enumerate() {
+			if i > 0 && i % size == 0 {
+				formatted.push(separator);
 			}
+			formatted.push(c);
+		}
 
-			id = chunks[1..].join(&separator.to_string());
+		formatted
+	}
+
+	fn normalize<'a>(&self, id: &'a str) -> Cow<'a, str> {
+		if !self.case_insensitive && self.confusables.is_empty() && self.group.is_none() {
+			return Cow::Borrowed(id);
 		}
 
-		ret
+		id.chars()
+			.filter(|&c| match self.group {
+				Some((_, separator)) => {
+					c != separator && (!c.is_whitespace() || self.alphabet.contains(&c))
+				}
+				None => true,
+			})
+			.map(|c| {
+				let folded = [c, c.to_ascii_lowercase(), c.to_ascii_uppercase()];
+				let candidates = if self.case_insensitive { &folded[..] } else { &folded[..1] };
+
+				candidates
+					.iter()
+					.find(|c| self.alphabet.contains(c))
+					.or_else(|| candidates.iter().find_map(|c| self.confusables.get(c)))
+					.copied()
+					.unwrap_or(c)
+			})
+			.collect()
 	}
 
-	fn encode_numbers(&self, numbers: &[u64], increment: usize) -> Result<String> {
+	fn encode_numbers<I>(&self, numbers: I, increment: usize) -> Result<String>
+	where
+		I: ExactSizeIterator<Item = u64> + Clone,
+	{
 		if increment > self.alphabet.len() {
 			return Err(Error::BlocklistMaxAttempts);
 		}
 
-		let mut offset = numbers.iter().enumerate().fold(numbers.len(), |a, (i, &v)| {
-			self.alphabet[v as usize % self.alphabet.len()] as usize + i + a
-		}) % self.alphabet.len();
+		let mut id = self.encode_attempt(numbers.clone(), increment);
 
-		offset = (offset + increment) % self.alphabet.len();
+		if self.is_blocked_id(&id) {
+			id = self.encode_numbers(numbers, increment + 1)?;
+		}
+
+		Ok(id)
+	}
+
+	fn offset(&self, numbers: impl ExactSizeIterator<Item = u64>) -> usize {
+		let len = numbers.len();
+		numbers.enumerate().fold(len, |a, (i, v)| a + constant::offset_term(&self.alphabet, i, v))
+			% self.alphabet.len()
+	}
+
+	/// Generate a sqid without checking it against the blocklist.
+	fn encode_attempt<I>(&self, numbers: I, increment: usize) -> String
+	where
+		I: ExactSizeIterator<Item = u64> + Clone,
+	{
+		let offset = (self.offset(numbers.clone()) + increment) % self.alphabet.len();
+		let len = numbers.len();
 
 		let mut alphabet: Vec<char> =
 			self.alphabet.iter().cycle().skip(offset).take(self.alphabet.len()).copied().collect();
@@ -286,10 +1018,10 @@ impl Sqids {
 
 		let mut ret: Vec<String> = vec![prefix.to_string()];
 
-		for (i, &num) in numbers.iter().enumerate() {
+		for (i, num) in numbers.enumerate() {
 			ret.push(self.to_id(num, &alphabet[1..]));
 
-			if i < numbers.len() - 1 {
+			if i < len - 1 {
 				ret.push(alphabet[0].to_string());
 				alphabet = Self::shuffle(&alphabet);
 			}
@@ -297,41 +1029,38 @@ impl Sqids {
 
 		let mut id = ret.join("");
 
-		if self.min_length as usize > id.len() {
+		// the check character counts towards the minimum length
+		let min_length = if self.check_character {
+			(self.min_length as usize).saturating_sub(1)
+		} else {
+			self.min_length as usize
+		};
+
+		if min_length > id.len() {
 			id += &alphabet[0].to_string();
 
-			while self.min_length as usize - id.len() > 0 {
+			while min_length - id.len() > 0 {
 				alphabet = Self::shuffle(&alphabet);
 
-				let slice_len = min(self.min_length as usize - id.len(), alphabet.len());
+				let slice_len = min(min_length - id.len(), alphabet.len());
 				let slice: Vec<char> = alphabet.iter().take(slice_len).cloned().collect();
 
 				id += &slice.iter().collect::<String>();
 			}
 		}
 
-		if self.is_blocked_id(&id) {
-			id = self.encode_numbers(numbers, increment + 1)?;
+		if self.check_character {
+			id.push(self.check_character_for(&id, &self.alphabet_index()));
 		}
 
-		Ok(id)
+		id
 	}
 
 	fn to_id(&self, num: u64, alphabet: &[char]) -> String {
-		let mut id = Vec::new();
-		let mut result = num;
-
-		loop {
-			let idx = (result % alphabet.len() as u64) as usize;
-			id.insert(0, alphabet[idx]);
-			result /= alphabet.len() as u64;
+		let mut digits = ['\0'; 64];
+		let start = constant::to_digits(num, alphabet, &mut digits);
 
-			if result == 0 {
-				break;
-			}
-		}
-
-		id.into_iter().collect()
+		digits[start..].iter().collect()
 	}
 
 	fn to_number(&self, id: &str, alphabet: &[char]) -> Option<u64> {
@@ -355,37 +1084,96 @@ impl Sqids {
 		Some(result)
 	}
 
-	fn shuffle(alphabet: &[char]) -> Vec<char> {
-		let mut chars: Vec<char> = alphabet.to_vec();
-
-		for i in 0..(chars.len() - 1) {
-			let j = chars.len() - 1 - i;
-			let r = (i as u32 * j as u32 + chars[i] as u32 + chars[j] as u32) % chars.len() as u32;
-			chars.swap(i, r as usize);
+	/// Luhn mod N over the positions of the characters in the shuffled alphabet. Detects every
+	/// single character substitution and every adjacent transposition except the one between the
+	/// first and the last character of the alphabet.
+	fn check_character_for(&self, id: &str, alphabet_index: &AlphabetIndex) -> char {
+		let base = self.alphabet.len();
+		let mut factor = 2;
+		let mut sum = 0;
+
+		for c in id.chars().rev() {
+			let addend = factor * position(alphabet_index, c).unwrap();
+			sum += addend / base + addend % base;
+			factor = if factor == 2 { 1 } else { 2 };
 		}
 
+		self.alphabet[(base - sum % base) % base]
+	}
+
+	fn shuffle(alphabet: &[char]) -> Vec<char> {
+		let mut chars: Vec<char> = alphabet.to_vec();
+		constant::shuffle(&mut chars);
 		chars
 	}
 
 	fn is_blocked_id(&self, id: &str) -> bool {
-		let id = id.to_lowercase();
+		self.blocklist.iter().any(|word| constant::is_blocked_by(id.as_bytes(), word.as_bytes()))
+	}
+}
 
-		for word in &self.blocklist {
-			if word.len() <= id.len() {
-				if id.len() <= 3 || word.len() <= 3 {
-					if id == *word {
-						return true;
-					}
-				} else if word.chars().any(|c| c.is_ascii_digit()) {
-					if id.starts_with(word) || id.ends_with(word) {
-						return true;
-					}
-				} else if id.contains(word) {
-					return true;
-				}
+/// The position of each character in the alphabet, by its code, alphabets being ASCII.
+type AlphabetIndex = [Option<u8>; 128];
+
+fn position(alphabet_index: &AlphabetIndex, c: char) -> Option<usize> {
+	alphabet_index.get(c as usize).copied().flatten().map(usize::from)
+}
+
+/// The numbers of a validated, non-empty sqid, decoded one at a time.
+struct Numbers<'a> {
+	sqids: &'a Sqids,
+	id: Cow<'a, str>,
+	/// Byte position of the next number in `id`, alphabet characters being ASCII
+	position: usize,
+	/// The alphabet of the next number, whose first character separates it from the following
+	/// one
+	alphabet: Vec<char>,
+}
+
+impl<'a> Numbers<'a> {
+	fn new(sqids: &'a Sqids, id: Cow<'a, str>) -> Self {
+		let prefix = id.chars().next().unwrap();
+		let offset = sqids.alphabet.iter().position(|&c| c == prefix).unwrap();
+		let alphabet: Vec<char> = sqids
+			.alphabet
+			.iter()
+			.cycle()
+			.skip(offset)
+			.take(sqids.alphabet.len())
+			.copied()
+			.collect();
+
+		Numbers { sqids, id, position: 1, alphabet: alphabet.into_iter().rev().collect() }
+	}
+}
+
+impl Iterator for Numbers<'_> {
+	type Item = u64;
+
+	fn next(&mut self) -> Option<u64> {
+		loop {
+			let rest = &self.id[self.position..];
+			let separator = self.alphabet[0];
+
+			let (chunk, more) = match rest.find(separator) {
+				Some(end) => (&rest[..end], true),
+				None => (rest, false),
+			};
+			if chunk.is_empty() {
+				self.position = self.id.len();
+				return None;
 			}
-		}
 
-		false
+			let number = self.sqids.to_number(chunk, &self.alphabet[1..]);
+			self.position += chunk.len() + usize::from(more);
+			if more {
+				self.alphabet = Sqids::shuffle(&self.alphabet);
+			}
+
+			// numbers that overflow are skipped
+			if number.is_some() {
+				return number;
+			}
+		}
 	}
 }
@@ -0,0 +1,131 @@
+use crate::{Error, Result};
+
+/// A value that can be encoded into a sqid, see [Sqids::encode_value](crate::Sqids::encode_value).
+///
+/// It is implemented for `u64`, tuples of up to 12 `u64`s, and arrays and `Vec`s of `u64`s, and
+/// can be implemented for small structs. Typed [Sqid](struct@crate::Sqid)s are not, as they are
+/// encoded with the [Sqids](crate::Sqids) of their marker type instead:
+///
+/// ```
+/// # use sqids::{FromSqidNumbers, Sqids, ToSqidNumbers};
+/// #[derive(Debug, PartialEq)]
+/// struct ItemKey {
+/// 	tenant_id: u64,
+/// 	item_id: u64,
+/// }
+///
+/// impl ToSqidNumbers for ItemKey {
+/// 	fn to_sqid_numbers(&self) -> impl ExactSizeIterator<Item = u64> + Clone {
+/// 		[self.tenant_id, self.item_id].into_iter()
+/// 	}
+/// }
+///
+/// impl FromSqidNumbers for ItemKey {
+/// 	fn from_sqid_numbers(numbers: Vec<u64>) -> sqids::Result<Self> {
+/// 		let (tenant_id, item_id) = FromSqidNumbers::from_sqid_numbers(numbers)?;
+/// 		Ok(ItemKey { tenant_id, item_id })
+/// 	}
+/// }
+///
+/// let sqids = Sqids::default();
+/// let id = sqids.encode_value(&ItemKey { tenant_id: 1, item_id: 2 })?;
+/// assert_eq!(sqids.decode_value::<ItemKey>(&id)?, ItemKey { tenant_id: 1, item_id: 2 });
+/// # Ok::<(), sqids::Error>(())
+/// ```
+pub trait ToSqidNumbers {
+	/// The numbers of the value, in order.
+	fn to_sqid_numbers(&self) -> impl ExactSizeIterator<Item = u64> + Clone;
+}
+
+/// A value that can be built from the numbers decoded from a sqid, see
+/// [Sqids::decode_value](crate::Sqids::decode_value) and [ToSqidNumbers].
+///
+/// ```
+/// # use sqids::{Error, FromSqidNumbers};
+/// assert_eq!(u64::from_sqid_numbers(vec![1]), Ok(1));
+/// assert_eq!(<(u64, u64)>::from_sqid_numbers(vec![1, 2]), Ok((1, 2)));
+/// assert_eq!(
+/// 	<(u64, u64)>::from_sqid_numbers(vec![1, 2, 3]),
+/// 	Err(Error::Arity { expected: 2, actual: 3 })
+/// );
+/// ```
+pub trait FromSqidNumbers: Sized {
+	/// Build the value from `numbers`, or return [Error::Arity] if their count does not fit it.
+	fn from_sqid_numbers(numbers: Vec<u64>) -> Result<Self>;
+}
+
+impl ToSqidNumbers for u64 {
+	fn to_sqid_numbers(&self) -> impl ExactSizeIterator<Item = u64> + Clone {
+		[*self].into_iter()
+	}
+}
+
+impl FromSqidNumbers for u64 {
+	fn from_sqid_numbers(numbers: Vec<u64>) -> Result<Self> {
+		<[u64; 1]>::from_sqid_numbers(numbers).map(|[number]| number)
+	}
+}
+
+macro_rules! impl_tuple {
+	($len:literal: $($n:ident),+) => {
+		impl ToSqidNumbers for ($(impl_tuple!(@u64 $n),)+) {
+			fn to_sqid_numbers(&self) -> impl ExactSizeIterator<Item = u64> + Clone {
+				let ($($n,)+) = *self;
+				[$($n),+].into_iter()
+			}
+		}
+
+		impl FromSqidNumbers for ($(impl_tuple!(@u64 $n),)+) {
+			fn from_sqid_numbers(numbers: Vec<u64>) -> Result<Self> {
+				let [$($n),+] = <[u64; $len]>::from_sqid_numbers(numbers)?;
+				Ok(($($n,)+))
+			}
+		}
+	};
+	(@u64 $n:ident) => { u64 };
+}
+
+impl_tuple!(1: n0);
+impl_tuple!(2: n0, n1);
+impl_tuple!(3: n0, n1, n2);
+impl_tuple!(4: n0, n1, n2, n3);
+impl_tuple!(5: n0, n1, n2, n3, n4);
+impl_tuple!(6: n0, n1, n2, n3, n4, n5);
+impl_tuple!(7: n0, n1, n2, n3, n4, n5, n6);
+impl_tuple!(8: n0, n1, n2, n3, n4, n5, n6, n7);
+impl_tuple!(9: n0, n1, n2, n3, n4, n5, n6, n7, n8);
+impl_tuple!(10: n0, n1, n2, n3, n4, n5, n6, n7, n8, n9);
+impl_tuple!(11: n0, n1, n2, n3, n4, n5, n6, n7, n8, n9, n10);
+impl_tuple!(12: n0, n1, n2, n3, n4, n5, n6, n7, n8, n9, n10, n11);
+
+impl<const N: usize> ToSqidNumbers for [u64; N] {
+	fn to_sqid_numbers(&self) -> impl ExactSizeIterator<Item = u64> + Clone {
+		self.iter().copied()
+	}
+}
+
+impl<const N: usize> FromSqidNumbers for [u64; N] {
+	fn from_sqid_numbers(numbers: Vec<u64>) -> Result<Self> {
+		numbers
+			.try_into()
+			.map_err(|numbers: Vec<u64>| Error::Arity { expected: N, actual: numbers.len() })
+	}
+}
+
+impl ToSqidNumbers for [u64] {
+	fn to_sqid_numbers(&self) -> impl ExactSizeIterator<Item = u64> + Clone {
+		self.iter().copied()
+	}
+}
+
+impl ToSqidNumbers for Vec<u64> {
+	fn to_sqid_numbers(&self) -> impl ExactSizeIterator<Item = u64> + Clone {
+		self.iter().copied()
+	}
+}
+
+impl FromSqidNumbers for Vec<u64> {
+	fn from_sqid_numbers(numbers: Vec<u64>) -> Result<Self> {
+		Ok(numbers)
+	}
+}
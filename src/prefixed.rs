@@ -0,0 +1,91 @@
+use crate::{Error, Result, Sqids};
+
+/// A [Sqids] wrapper that generates self-describing sqids such as `usr_86Rf07`.
+///
+/// Each entity type `E` is registered with its own prefix. [PrefixedSqids::decode] tells which
+/// entity a sqid belongs to, so IDs of different entities cannot be mixed up.
+///
+/// ```
+/// # use sqids::{PrefixedSqids, Sqids};
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum Entity {
+/// 	User,
+/// 	Invoice,
+/// }
+///
+/// let mut sqids = PrefixedSqids::new(Sqids::default());
+/// sqids.register(Entity::User, "usr_")?;
+/// sqids.register(Entity::Invoice, "inv_")?;
+///
+/// let id = sqids.encode(Entity::User, &[1, 2, 3])?; // "usr_86Rf07"
+/// assert_eq!(sqids.decode(&id)?, (Entity::User, vec![1, 2, 3]));
+/// # Ok::<(), sqids::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct PrefixedSqids<E> {
+	sqids: Sqids,
+	prefixes: Vec<(E, String)>,
+}
+
+impl<E: Copy + PartialEq> PrefixedSqids<E> {
+	/// Create a [PrefixedSqids] without any registered prefixes.
+	pub fn new(sqids: Sqids) -> Self {
+		PrefixedSqids { sqids, prefixes: Vec::new() }
+	}
+
+	/// Register the prefix of an entity.
+	///
+	/// The prefix must end with a delimiter that is not in the alphabet, such as `_`, so it
+	/// cannot be confused with the sqid itself. Registering an entity twice, or a prefix that
+	/// starts with or is the start of another prefix, returns [Error::PrefixAmbiguous].
+	pub fn register(&mut self, entity: E, prefix: impl Into<String>) -> Result<()> {
+		let prefix = prefix.into();
+
+		match prefix.chars().last() {
+			Some(delimiter) if !self.sqids.alphabet.contains(&delimiter) => {}
+			_ => return Err(Error::PrefixDelimiter),
+		}
+
+		if self.prefixes.iter().any(|(e, p)| {
+			*e == entity || p.starts_with(prefix.as_str()) || prefix.starts_with(p.as_str())
+		}) {
+			return Err(Error::PrefixAmbiguous);
+		}
+
+		self.prefixes.push((entity, prefix));
+
+		Ok(())
+	}
+
+	/// Generate a sqid for `entity` from a slice of numbers, see [Sqids::encode].
+	pub fn encode(&self, entity: E, numbers: &[u64]) -> Result<String> {
+		let prefix = self.prefix(entity).ok_or(Error::PrefixUnknown)?;
+
+		Ok(format!("{prefix}{}", self.sqids.encode(numbers)?))
+	}
+
+	/// Decode a prefixed sqid into the entity it belongs to and its numbers, see
+	/// [Sqids::try_decode].
+	pub fn decode(&self, id: &str) -> Result<(E, Vec<u64>)> {
+		let (entity, prefix) = self
+			.prefixes
+			.iter()
+			.find(|(_, prefix)| id.starts_with(prefix.as_str()))
+			.ok_or(Error::PrefixUnknown)?;
+
+		match self.sqids.try_decode(&id[prefix.len()..])? {
+			numbers if numbers.is_empty() => Err(Error::InvalidId),
+			numbers => Ok((*entity, numbers)),
+		}
+	}
+
+	/// The prefix registered for `entity`.
+	pub fn prefix(&self, entity: E) -> Option<&str> {
+		self.prefixes.iter().find(|(e, _)| *e == entity).map(|(_, prefix)| prefix.as_str())
+	}
+
+	/// The [Sqids] used to generate the unprefixed part of the sqids.
+	pub fn sqids(&self) -> &Sqids {
+		&self.sqids
+	}
+}
@@ -0,0 +1,98 @@
+//! A process-wide registry of named [Sqids], for code that needs a shared configuration without
+//! having it passed through every call.
+//!
+//! Registered [Sqids] live for the rest of the program. Lookups are lock-free; registering
+//! copies the registry, so it is meant to be done at startup, after which the registry can be
+//! frozen to make it immutable.
+//!
+//! ```
+//! let sqids = sqids::Sqids::builder().min_length(8).build()?;
+//! sqids::register("orders", sqids)?;
+//! sqids::registry::freeze();
+//!
+//! assert_eq!(sqids::get("orders").unwrap().encode(&[1])?, "UkLWZg9D");
+//! assert!(sqids::get("users").is_none());
+//! # Ok::<(), sqids::Error>(())
+//! ```
+//!
+//! Typed IDs can use a registered [Sqids] with `#[sqid(registry = "...")]`, or by implementing
+//! [SqidsConfig](crate::SqidsConfig) with [get]. The serde helpers can use one with
+//! [SqidsSeed::registered](crate::serde::SqidsSeed::registered) and
+//! [SerializeWith::registered](crate::serde::SerializeWith::registered), and `with` helpers
+//! for one are defined with [serde_registered](crate::serde_registered).
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, LazyLock, Mutex, PoisonError},
+};
+
+use arc_swap::ArcSwap;
+
+use crate::{Error, Result, Sqids};
+
+static REGISTRY: LazyLock<ArcSwap<Registry>> = LazyLock::new(Default::default);
+
+/// Serializes the changes to the registry, lookups do not take it.
+static WRITER: Mutex<()> = Mutex::new(());
+
+#[derive(Clone, Default)]
+struct Registry {
+	sqids: HashMap<String, &'static Sqids>,
+	frozen: bool,
+}
+
+/// Register `sqids` under `name`.
+///
+/// Returns [Error::AlreadyRegistered] if a [Sqids] is already registered under `name`, or
+/// [Error::RegistryFrozen] if the registry is frozen. `sqids` is then dropped rather than leaked.
+pub fn register(name: &str, sqids: Sqids) -> Result<()> {
+	update(|registry| {
+		if registry.frozen {
+			return Err(Error::RegistryFrozen);
+		}
+		if registry.sqids.contains_key(name) {
+			return Err(Error::AlreadyRegistered);
+		}
+
+		// only leaked once it is certain to be registered
+		let mut registry = registry.clone();
+		registry.sqids.insert(name.to_string(), Box::leak(Box::new(sqids)));
+		Ok(registry)
+	})
+}
+
+/// The [Sqids] registered under `name`.
+pub fn get(name: &str) -> Option<&'static Sqids> {
+	REGISTRY.load().sqids.get(name).copied()
+}
+
+/// Make the registry immutable: any further [register] fails with [Error::RegistryFrozen].
+///
+/// ```
+/// # use sqids::{Sqids, Error};
+/// sqids::register("orders", Sqids::default())?;
+/// sqids::registry::freeze();
+///
+/// assert!(sqids::registry::is_frozen());
+/// assert_eq!(sqids::register("users", Sqids::default()), Err(Error::RegistryFrozen));
+/// assert!(sqids::get("orders").is_some());
+/// # Ok::<(), Error>(())
+/// ```
+pub fn freeze() {
+	// freezing cannot fail, and freezing twice is harmless
+	let _ = update(|registry| Ok(Registry { frozen: true, ..registry.clone() }));
+}
+
+/// Whether the registry is frozen, see [freeze].
+pub fn is_frozen() -> bool {
+	REGISTRY.load().frozen
+}
+
+/// Replace the registry with `f` applied to it, which runs once since changes are serialized.
+fn update(f: impl FnOnce(&Registry) -> Result<Registry>) -> Result<()> {
+	let _writer = WRITER.lock().unwrap_or_else(PoisonError::into_inner);
+
+	let next = f(&REGISTRY.load())?;
+	REGISTRY.store(Arc::new(next));
+	Ok(())
+}
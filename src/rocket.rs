@@ -0,0 +1,77 @@
+//! [Rocket](https://docs.rs/rocket) support for typed IDs.
+//!
+//! [Sqid](struct@Sqid)s can be route parameters and form fields, and render as sqids in `uri!`.
+//! Rocket parses parameters without access to managed state, so the [Sqids](crate::Sqids) comes
+//! from the [SqidsConfig] of the marker type (see [integrations](struct@Sqid#integrations)). To
+//! configure it at launch rather than in code, register it and look it up with
+//! `sqids::get` from the `registry` feature.
+//!
+//! ```
+//! # use rocket::{get, routes, uri};
+//! # use sqids::{Sqid, Sqids, SqidsConfig};
+//! struct Order;
+//!
+//! impl SqidsConfig for Order {
+//! 	fn sqids() -> &'static Sqids {
+//! 		static SQIDS: std::sync::LazyLock<Sqids> = std::sync::LazyLock::new(Sqids::default);
+//! 		&SQIDS
+//! 	}
+//! }
+//!
+//! #[get("/orders/<id>")]
+//! fn order(id: Sqid<Order>) -> String {
+//! 	format!("order {:?}", id.numbers())
+//! }
+//!
+//! assert_eq!(uri!(order(Sqid::<Order>::from(1))).to_string(), "/orders/Uk");
+//! # let _ = routes![order];
+//! ```
+
+use std::fmt;
+
+use ::rocket::{
+	form::{self, FromFormField, ValueField},
+	http::uri::fmt::{FromUriParam, Part, UriDisplay},
+	request::FromParam,
+};
+
+use crate::{Error, Sqid, SqidsConfig};
+
+impl<'a, T: SqidsConfig> FromParam<'a> for Sqid<T> {
+	type Error = Error;
+
+	fn from_param(param: &'a str) -> Result<Self, Error> {
+		param.parse()
+	}
+}
+
+impl<'v, T: SqidsConfig> FromFormField<'v> for Sqid<T> {
+	fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+		field
+			.value
+			.parse()
+			.map_err(|error: Error| form::Error::validation(error.to_string()).into())
+	}
+}
+
+impl<P: Part, T: SqidsConfig> UriDisplay<P> for Sqid<T> {
+	fn fmt(&self, f: &mut ::rocket::http::uri::fmt::Formatter<'_, P>) -> fmt::Result {
+		f.write_value(&self.encode().map_err(|_| fmt::Error)?)
+	}
+}
+
+impl<P: Part, T: SqidsConfig> FromUriParam<P, Sqid<T>> for Sqid<T> {
+	type Target = Sqid<T>;
+
+	fn from_uri_param(param: Sqid<T>) -> Sqid<T> {
+		param
+	}
+}
+
+impl<'a, P: Part, T: SqidsConfig> FromUriParam<P, &'a Sqid<T>> for Sqid<T> {
+	type Target = &'a Sqid<T>;
+
+	fn from_uri_param(param: &'a Sqid<T>) -> &'a Sqid<T> {
+		param
+	}
+}
@@ -0,0 +1,330 @@
+//! SQL functions for [rusqlite](https://docs.rs/rusqlite) connections, to convert between sqids
+//! and row IDs in queries.
+//!
+//! [register_functions] registers:
+//!
+//! - `sqids_encode(config, n1, n2, ...)`: the sqid of the numbers
+//! - `sqids_decode(config, id)`: the numbers of a sqid, as a JSON array
+//! - `sqids_decode_each([config,] id)`: a table of the numbers of a sqid, in a `value` column,
+//!   decoded with the default configuration when `config` is left out
+//!
+//! `config` is a JSON object with the optional `alphabet`, `min_length` and `blocklist` keys
+//! of the [Sqids] builder; `'{}'` or `NULL` is the default configuration. Up to
+//! [MAX_CACHED_CONFIGS] configurations are kept parsed per connection. `NULL` inputs give `NULL`
+//! or no rows.
+//!
+//! ```
+//! # use rusqlite::Connection;
+//! let connection = Connection::open_in_memory()?;
+//! sqids::rusqlite::register_functions(&connection)?;
+//!
+//! let id: String =
+//! 	connection.query_row(r#"SELECT sqids_encode('{"min_length": 8}', 1, 2, 3)"#, [], |row| {
+//! 		row.get(0)
+//! 	})?;
+//! assert_eq!(id, "86Rf07xd");
+//!
+//! let numbers: String =
+//! 	connection.query_row("SELECT sqids_decode('{}', '86Rf07')", [], |row| row.get(0))?;
+//! assert_eq!(numbers, "[1,2,3]");
+//!
+//! let total: i64 = connection.query_row(
+//! 	"SELECT sum(value) FROM sqids_decode_each('{}', '86Rf07')",
+//! 	[],
+//! 	|row| row.get(0),
+//! )?;
+//! assert_eq!(total, 6);
+//! # Ok::<(), rusqlite::Error>(())
+//! ```
+
+use std::{
+	collections::HashMap,
+	marker::PhantomData,
+	os::raw::c_int,
+	sync::{Arc, Mutex},
+};
+
+use ::rusqlite::{
+	ffi,
+	functions::FunctionFlags,
+	vtab::{
+		self, eponymous_only_module, IndexConstraintOp, IndexInfo, VTab, VTabConfig,
+		VTabConnection, VTabCursor, Values,
+	},
+	Connection,
+};
+use serde_json::Value;
+
+use crate::{Error, Sqids};
+
+/// The maximum number of parsed configurations kept per connection, the cache is emptied when it
+/// is full.
+pub const MAX_CACHED_CONFIGS: usize = 16;
+
+/// Parsed configurations of a connection, by their JSON.
+type Cache = Arc<Mutex<HashMap<String, Arc<Sqids>>>>;
+
+/// Register the `sqids_encode`, `sqids_decode` and `sqids_decode_each` functions on
+/// `connection`, see the [module](self) documentation.
+pub fn register_functions(connection: &Connection) -> rusqlite::Result<()> {
+	let cache = Cache::default();
+	let flags = FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC;
+
+	let encode_cache = cache.clone();
+	connection.create_scalar_function("sqids_encode", -1, flags, move |ctx| {
+		if ctx.is_empty() {
+			return Err(user_error("sqids_encode requires a configuration"));
+		}
+
+		let sqids = sqids(&encode_cache, ctx.get(0)?)?;
+		let numbers = (1..ctx.len())
+			.map(|i| ctx.get::<Option<i64>>(i).map(|number| number.map(u64::try_from)))
+			.collect::<rusqlite::Result<Option<Result<Vec<_>, _>>>>()?;
+
+		match numbers {
+			Some(numbers) => {
+				let numbers =
+					numbers.map_err(|_| user_error("sqids cannot encode negative numbers"))?;
+				sqids.encode(&numbers).map(Some).map_err(sqids_error)
+			}
+			None => Ok(None),
+		}
+	})?;
+
+	let decode_cache = cache.clone();
+	connection.create_scalar_function("sqids_decode", 2, flags, move |ctx| {
+		let sqids = sqids(&decode_cache, ctx.get(0)?)?;
+
+		match ctx.get::<Option<String>>(1)? {
+			Some(id) => {
+				let numbers = sqids.try_decode(&id).map_err(sqids_error)?;
+				Ok(Some(Value::from(numbers).to_string()))
+			}
+			None => Ok(None),
+		}
+	})?;
+
+	connection.create_module(
+		"sqids_decode_each",
+		eponymous_only_module::<DecodeEachTab>(),
+		Some(cache),
+	)
+}
+
+fn sqids(cache: &Cache, config: Option<String>) -> rusqlite::Result<Arc<Sqids>> {
+	let config = config.unwrap_or_default();
+
+	let mut cache = cache.lock().unwrap();
+	if let Some(sqids) = cache.get(&config) {
+		return Ok(sqids.clone());
+	}
+
+	let sqids = Arc::new(parse_config(&config)?);
+	if cache.len() >= MAX_CACHED_CONFIGS {
+		cache.clear();
+	}
+	cache.insert(config, sqids.clone());
+	Ok(sqids)
+}
+
+fn parse_config(config: &str) -> rusqlite::Result<Sqids> {
+	let mut builder = Sqids::builder();
+	if config.is_empty() {
+		return builder.build().map_err(sqids_error);
+	}
+
+	let invalid = |key: &str| user_error(format!("invalid sqids configuration `{key}`"));
+
+	let config: Value =
+		serde_json::from_str(config).map_err(|error| user_error(error.to_string()))?;
+	let Value::Object(config) = config else {
+		return Err(user_error("the sqids configuration must be a JSON object"));
+	};
+
+	for (key, value) in config {
+		builder = match key.as_str() {
+			"alphabet" => {
+				builder.alphabet(value.as_str().ok_or_else(|| invalid(&key))?.chars().collect())
+			}
+			"min_length" => builder.min_length(
+				value
+					.as_u64()
+					.and_then(|length| u8::try_from(length).ok())
+					.ok_or_else(|| invalid(&key))?,
+			),
+			"blocklist" => builder.blocklist(
+				value
+					.as_array()
+					.and_then(|words| {
+						words.iter().map(|word| word.as_str().map(str::to_string)).collect()
+					})
+					.ok_or_else(|| invalid(&key))?,
+			),
+			_ => return Err(user_error(format!("unknown sqids configuration `{key}`"))),
+		};
+	}
+
+	builder.build().map_err(sqids_error)
+}
+
+fn user_error(message: impl Into<String>) -> rusqlite::Error {
+	rusqlite::Error::UserFunctionError(message.into().into())
+}
+
+fn sqids_error(error: Error) -> rusqlite::Error {
+	rusqlite::Error::UserFunctionError(Box::new(error))
+}
+
+// Columns of `sqids_decode_each`, the arguments are hidden columns
+const COLUMN_VALUE: c_int = 0;
+const COLUMN_CONFIG: c_int = 1;
+const COLUMN_ID: c_int = 2;
+
+/// Bits of the index number, for the arguments given to `sqids_decode_each`
+const HAS_CONFIG: c_int = 1;
+const HAS_ID: c_int = 2;
+
+#[repr(C)]
+struct DecodeEachTab {
+	/// Base class, must be first
+	base: ffi::sqlite3_vtab,
+	cache: Cache,
+}
+
+unsafe impl<'vtab> VTab<'vtab> for DecodeEachTab {
+	type Aux = Cache;
+	type Cursor = DecodeEachCursor<'vtab>;
+
+	fn connect(
+		db: &mut VTabConnection,
+		aux: Option<&Cache>,
+		_args: &[&[u8]],
+	) -> rusqlite::Result<(String, Self)> {
+		db.config(VTabConfig::Innocuous)?;
+
+		let vtab = DecodeEachTab {
+			base: ffi::sqlite3_vtab::default(),
+			cache: aux.cloned().unwrap_or_default(),
+		};
+		Ok(("CREATE TABLE x(value, config HIDDEN, id HIDDEN)".to_string(), vtab))
+	}
+
+	fn best_index(&self, info: &mut IndexInfo) -> rusqlite::Result<()> {
+		let mut arguments = [None, None];
+		for (i, constraint) in info.constraints().enumerate() {
+			let argument = match constraint.column() {
+				COLUMN_CONFIG => 0,
+				COLUMN_ID => 1,
+				_ => continue,
+			};
+			if !constraint.is_usable()
+				|| constraint.operator() != IndexConstraintOp::SQLITE_INDEX_CONSTRAINT_EQ
+			{
+				// the arguments can only be given directly
+				return Err(rusqlite::Error::SqliteFailure(
+					ffi::Error::new(ffi::SQLITE_CONSTRAINT),
+					None,
+				));
+			}
+			arguments[argument] = Some(i);
+		}
+
+		let mut idx_num = 0;
+		let mut argv_index = 0;
+		for (argument, bit) in arguments.into_iter().zip([HAS_CONFIG, HAS_ID]) {
+			if let Some(i) = argument {
+				argv_index += 1;
+				let mut usage = info.constraint_usage(i);
+				usage.set_argv_index(argv_index);
+				usage.set_omit(true);
+				idx_num |= bit;
+			}
+		}
+
+		info.set_idx_num(idx_num);
+		info.set_estimated_rows(10);
+		Ok(())
+	}
+
+	fn open(&'vtab mut self) -> rusqlite::Result<DecodeEachCursor<'vtab>> {
+		Ok(DecodeEachCursor {
+			base: ffi::sqlite3_vtab_cursor::default(),
+			cache: self.cache.clone(),
+			numbers: Vec::new(),
+			row: 0,
+			id: None,
+			config: None,
+			phantom: PhantomData,
+		})
+	}
+}
+
+#[repr(C)]
+struct DecodeEachCursor<'vtab> {
+	/// Base class, must be first
+	base: ffi::sqlite3_vtab_cursor,
+	cache: Cache,
+	numbers: Vec<u64>,
+	row: usize,
+	id: Option<String>,
+	config: Option<String>,
+	phantom: PhantomData<&'vtab DecodeEachTab>,
+}
+
+unsafe impl VTabCursor for DecodeEachCursor<'_> {
+	fn filter(
+		&mut self,
+		idx_num: c_int,
+		_idx_str: Option<&str>,
+		args: &Values<'_>,
+	) -> rusqlite::Result<()> {
+		let mut i = 0;
+		let mut next_arg = |bit| -> rusqlite::Result<Option<String>> {
+			if idx_num & bit == 0 {
+				return Ok(None);
+			}
+			i += 1;
+			args.get(i - 1)
+		};
+		self.config = next_arg(HAS_CONFIG)?;
+		self.id = next_arg(HAS_ID)?;
+		if idx_num == HAS_CONFIG {
+			// `sqids_decode_each(id)` binds its only argument to the first hidden column
+			self.id = self.config.take();
+		}
+
+		self.numbers = match &self.id {
+			Some(id) => {
+				sqids(&self.cache, self.config.clone())?.try_decode(id).map_err(sqids_error)?
+			}
+			None => Vec::new(),
+		};
+		self.row = 0;
+		Ok(())
+	}
+
+	fn next(&mut self) -> rusqlite::Result<()> {
+		self.row += 1;
+		Ok(())
+	}
+
+	fn eof(&self) -> bool {
+		self.row >= self.numbers.len()
+	}
+
+	fn column(&self, ctx: &mut vtab::Context, i: c_int) -> rusqlite::Result<()> {
+		match i {
+			COLUMN_VALUE => {
+				let number = i64::try_from(self.numbers[self.row])
+					.map_err(|_| user_error("sqid number does not fit in an INTEGER"))?;
+				ctx.set_result(&number)
+			}
+			COLUMN_CONFIG => ctx.set_result(&self.config),
+			_ => ctx.set_result(&self.id),
+		}
+	}
+
+	fn rowid(&self) -> rusqlite::Result<i64> {
+		Ok(self.row as i64)
+	}
+}
@@ -0,0 +1,486 @@
+//! Helpers for serializing numeric fields as sqids with `#[serde(with = "...")]`.
+//!
+//! The sqids are generated with the global [Sqids], which is [Sqids::default] unless another one
+//! is set with [set_global]. Deserialization only accepts canonical sqids of a single number (see
+//! [Sqids::decode_canonical]).
+//!
+//! ```
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Order {
+//! 	#[serde(with = "sqids::serde::as_sqid")]
+//! 	id: u64,
+//! 	#[serde(with = "sqids::serde::as_sqid_option")]
+//! 	parent_id: Option<u64>,
+//! 	#[serde(with = "sqids::serde::as_sqid_vec")]
+//! 	item_ids: Vec<u64>,
+//! }
+//!
+//! let order = Order { id: 1, parent_id: None, item_ids: vec![2, 3] };
+//! let json = serde_json::to_string(&order)?;
+//! assert_eq!(json, r#"{"id":"Uk","parent_id":null,"item_ids":["gb","Ef"]}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! With the `registry` feature, `sqids::serde_registered!` defines the same helpers for a [Sqids]
+//! registered under a name.
+
+use std::{fmt, marker::PhantomData, sync::OnceLock};
+
+use ::serde::{
+	de::{self, DeserializeSeed},
+	Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Error, Sqids};
+
+static GLOBAL: OnceLock<Sqids> = OnceLock::new();
+
+/// Set the [Sqids] used by the serde helpers. It can only be set once, before it is first used;
+/// otherwise [Error::AlreadyRegistered] is returned.
+///
+/// ```
+/// let sqids = sqids::Sqids::builder().min_length(8).build()?;
+/// assert!(sqids::serde::set_global(sqids).is_ok());
+/// assert_eq!(serde_json::to_string(&sqids::serde::AsSqid(1))?, r#""UkLWZg9D""#);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn set_global(sqids: Sqids) -> crate::Result<()> {
+	GLOBAL.set(sqids).map_err(|_| Error::AlreadyRegistered)
+}
+
+/// The [Sqids] used by the serde helpers.
+pub fn global() -> &'static Sqids {
+	GLOBAL.get_or_init(Sqids::default)
+}
+
+/// A number that is (de)serialized as a sqid with the [global] [Sqids].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct AsSqid(pub u64);
+
+impl Serialize for AsSqid {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_numbers(global(), &[self.0], serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for AsSqid {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		SqidsSeed::<u64>::new(global()).deserialize(deserializer).map(AsSqid)
+	}
+}
+
+/// Serialize a `u64` as a sqid.
+pub mod as_sqid {
+	use super::*;
+
+	/// Serialize a `u64` as a sqid.
+	pub fn serialize<S: Serializer>(number: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+		AsSqid(*number).serialize(serializer)
+	}
+
+	/// Deserialize a `u64` from a sqid.
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+		AsSqid::deserialize(deserializer).map(|sqid| sqid.0)
+	}
+}
+
+/// Serialize an `Option<u64>` as an optional sqid.
+pub mod as_sqid_option {
+	use super::*;
+
+	/// Serialize an `Option<u64>` as an optional sqid.
+	pub fn serialize<S: Serializer>(
+		number: &Option<u64>,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		number.map(AsSqid).serialize(serializer)
+	}
+
+	/// Deserialize an `Option<u64>` from an optional sqid.
+	pub fn deserialize<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Option<u64>, D::Error> {
+		Option::<AsSqid>::deserialize(deserializer).map(|sqid| sqid.map(|sqid| sqid.0))
+	}
+}
+
+/// Serialize a `Vec<u64>` as a sequence of sqids, one for each number.
+pub mod as_sqid_vec {
+	use super::*;
+
+	/// Serialize a `Vec<u64>` as a sequence of sqids.
+	pub fn serialize<S: Serializer>(numbers: &[u64], serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_seq(numbers.iter().map(|&number| AsSqid(number)))
+	}
+
+	/// Deserialize a `Vec<u64>` from a sequence of sqids.
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u64>, D::Error> {
+		Vec::<AsSqid>::deserialize(deserializer)
+			.map(|sqids| sqids.into_iter().map(|s| s.0).collect())
+	}
+}
+
+/// A [DeserializeSeed] that decodes a sqid with a [Sqids] chosen at runtime, for example the
+/// one of the current tenant.
+///
+/// `T` is the type of the decoded value:
+///
+/// - `u64`: a sqid of a single number
+/// - `[u64; N]`: a sqid of exactly `N` numbers
+/// - `Vec<u64>`: a sqid of any number of numbers
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use sqids::{serde::SqidsSeed, Sqids};
+/// let sqids = Sqids::builder().min_length(8).build()?;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(r#""86Rf07xd""#);
+/// let numbers = SqidsSeed::<[u64; 3]>::new(&sqids).deserialize(&mut deserializer)?;
+/// assert_eq!(numbers, [1, 2, 3]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct SqidsSeed<'a, T = u64> {
+	sqids: &'a Sqids,
+	marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> SqidsSeed<'a, T> {
+	/// Create a [SqidsSeed] that decodes with `sqids`.
+	pub fn new(sqids: &'a Sqids) -> Self {
+		SqidsSeed { sqids, marker: PhantomData }
+	}
+}
+
+#[cfg(feature = "registry")]
+impl<T> SqidsSeed<'static, T> {
+	/// Create a [SqidsSeed] that decodes with the [Sqids] registered under `name`, see
+	/// [registry](crate::registry).
+	pub fn registered(name: &str) -> Option<Self> {
+		crate::registry::get(name).map(SqidsSeed::new)
+	}
+}
+
+impl<'de> DeserializeSeed<'de> for SqidsSeed<'_, u64> {
+	type Value = u64;
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<u64, D::Error> {
+		deserializer.deserialize_str(NumbersVisitor::new(self.sqids, Some(1))).map(|n| n[0])
+	}
+}
+
+impl<'de, const N: usize> DeserializeSeed<'de> for SqidsSeed<'_, [u64; N]> {
+	type Value = [u64; N];
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<[u64; N], D::Error> {
+		let numbers = deserializer.deserialize_str(NumbersVisitor::new(self.sqids, Some(N)))?;
+		Ok(numbers.try_into().unwrap())
+	}
+}
+
+impl<'de> DeserializeSeed<'de> for SqidsSeed<'_, Vec<u64>> {
+	type Value = Vec<u64>;
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Vec<u64>, D::Error> {
+		deserializer.deserialize_str(NumbersVisitor::new(self.sqids, None))
+	}
+}
+
+/// Serializes a value as a sqid with a [Sqids] chosen at runtime, the counterpart of
+/// [SqidsSeed].
+///
+/// ```
+/// # use sqids::{serde::SerializeWith, Sqids};
+/// let sqids = Sqids::builder().min_length(8).build()?;
+///
+/// let json = serde_json::to_string(&SerializeWith::new(&sqids, &[1, 2, 3]))?;
+/// assert_eq!(json, r#""86Rf07xd""#);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct SerializeWith<'a, T: ?Sized> {
+	sqids: &'a Sqids,
+	value: &'a T,
+}
+
+impl<'a, T: ?Sized> SerializeWith<'a, T> {
+	/// Create a [SerializeWith] that encodes `value` with `sqids`.
+	pub fn new(sqids: &'a Sqids, value: &'a T) -> Self {
+		SerializeWith { sqids, value }
+	}
+}
+
+#[cfg(feature = "registry")]
+impl<'a, T: ?Sized> SerializeWith<'a, T> {
+	/// Create a [SerializeWith] that encodes `value` with the [Sqids] registered under `name`,
+	/// see [registry](crate::registry).
+	pub fn registered(name: &str, value: &'a T) -> Option<Self> {
+		crate::registry::get(name).map(|sqids| SerializeWith::new(sqids, value))
+	}
+}
+
+impl Serialize for SerializeWith<'_, u64> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_numbers(self.sqids, &[*self.value], serializer)
+	}
+}
+
+impl<const N: usize> Serialize for SerializeWith<'_, [u64; N]> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_numbers(self.sqids, self.value, serializer)
+	}
+}
+
+impl Serialize for SerializeWith<'_, [u64]> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_numbers(self.sqids, self.value, serializer)
+	}
+}
+
+impl Serialize for SerializeWith<'_, Vec<u64>> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_numbers(self.sqids, self.value, serializer)
+	}
+}
+
+fn serialize_numbers<S: Serializer>(
+	sqids: &Sqids,
+	numbers: &[u64],
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	let id = sqids.encode(numbers).map_err(::serde::ser::Error::custom)?;
+	serializer.serialize_str(&id)
+}
+
+/// Decodes a canonical sqid of `len` numbers, or of any number of numbers if `len` is `None`.
+struct NumbersVisitor<'a> {
+	sqids: &'a Sqids,
+	len: Option<usize>,
+}
+
+impl<'a> NumbersVisitor<'a> {
+	fn new(sqids: &'a Sqids, len: Option<usize>) -> Self {
+		NumbersVisitor { sqids, len }
+	}
+}
+
+impl de::Visitor<'_> for NumbersVisitor<'_> {
+	type Value = Vec<u64>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.len {
+			Some(len) if len != 1 => write!(f, "a sqid of {len} numbers"),
+			_ => f.write_str("a sqid"),
+		}
+	}
+
+	fn visit_str<E: de::Error>(self, id: &str) -> Result<Vec<u64>, E> {
+		match self.sqids.decode_canonical(id) {
+			// a sqid of several numbers is not a sqid of a single number, nor is an empty one
+			Ok(numbers) if numbers.is_empty() || self.len == Some(1) && numbers.len() != 1 => {
+				Err(E::custom(format_args!("invalid sqid {id:?}: {}", Error::InvalidId)))
+			}
+			Ok(numbers) if self.len.is_some_and(|len| len != numbers.len()) => {
+				Err(E::invalid_length(numbers.len(), &self))
+			}
+			Ok(numbers) => Ok(numbers),
+			Err(error) => Err(E::custom(format_args!("invalid sqid {id:?}: {error}"))),
+		}
+	}
+}
+
+/// Define `with` helpers like [as_sqid], [as_sqid_option] and [as_sqid_vec] that use the [Sqids]
+/// registered under a name, see [registry](crate::registry), instead of the [global] one.
+///
+/// A `with` module is named by a path, so the name of the [Sqids] cannot be passed to it; this
+/// defines a module with the three helpers for one name instead. The [Sqids] is looked up on
+/// every use, and (de)serialization fails if nothing is registered under the name.
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// sqids::serde_registered!(pub mod order_sqids = "orders");
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Order {
+/// 	#[serde(with = "order_sqids::as_sqid")]
+/// 	id: u64,
+/// 	#[serde(with = "order_sqids::as_sqid_option")]
+/// 	parent_id: Option<u64>,
+/// 	#[serde(with = "order_sqids::as_sqid_vec")]
+/// 	item_ids: Vec<u64>,
+/// }
+///
+/// sqids::register("orders", sqids::Sqids::builder().min_length(8).build()?)?;
+///
+/// let order = Order { id: 1, parent_id: None, item_ids: vec![2] };
+/// let json = serde_json::to_string(&order)?;
+/// assert_eq!(json, r#"{"id":"UkLWZg9D","parent_id":null,"item_ids":["gbHJdmfr"]}"#);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "registry")]
+#[macro_export]
+macro_rules! serde_registered {
+	($vis:vis mod $module:ident = $name:literal) => {
+		$vis mod $module {
+			/// Serialize a `u64` as a sqid.
+			pub mod as_sqid {
+				use $crate::__private::serde::{Deserializer, Serializer};
+
+				/// Serialize a `u64` as a sqid.
+				pub fn serialize<S: Serializer>(
+					number: &u64,
+					serializer: S,
+				) -> ::std::result::Result<S::Ok, S::Error> {
+					$crate::serde::__registered::serialize($name, number, serializer)
+				}
+
+				/// Deserialize a `u64` from a sqid.
+				pub fn deserialize<'de, D: Deserializer<'de>>(
+					deserializer: D,
+				) -> ::std::result::Result<u64, D::Error> {
+					$crate::serde::__registered::deserialize($name, deserializer)
+				}
+			}
+
+			/// Serialize an `Option<u64>` as an optional sqid.
+			pub mod as_sqid_option {
+				use $crate::__private::serde::{Deserializer, Serializer};
+
+				/// Serialize an `Option<u64>` as an optional sqid.
+				pub fn serialize<S: Serializer>(
+					number: &Option<u64>,
+					serializer: S,
+				) -> ::std::result::Result<S::Ok, S::Error> {
+					$crate::serde::__registered::serialize_option($name, number, serializer)
+				}
+
+				/// Deserialize an `Option<u64>` from an optional sqid.
+				pub fn deserialize<'de, D: Deserializer<'de>>(
+					deserializer: D,
+				) -> ::std::result::Result<Option<u64>, D::Error> {
+					$crate::serde::__registered::deserialize_option($name, deserializer)
+				}
+			}
+
+			/// Serialize a `Vec<u64>` as a sequence of sqids, one for each number.
+			pub mod as_sqid_vec {
+				use $crate::__private::serde::{Deserializer, Serializer};
+
+				/// Serialize a `Vec<u64>` as a sequence of sqids.
+				pub fn serialize<S: Serializer>(
+					numbers: &[u64],
+					serializer: S,
+				) -> ::std::result::Result<S::Ok, S::Error> {
+					$crate::serde::__registered::serialize_vec($name, numbers, serializer)
+				}
+
+				/// Deserialize a `Vec<u64>` from a sequence of sqids.
+				pub fn deserialize<'de, D: Deserializer<'de>>(
+					deserializer: D,
+				) -> ::std::result::Result<Vec<u64>, D::Error> {
+					$crate::serde::__registered::deserialize_vec($name, deserializer)
+				}
+			}
+		}
+	};
+}
+
+// Used by the modules defined with `serde_registered!`, not public API.
+#[cfg(feature = "registry")]
+#[doc(hidden)]
+pub mod __registered {
+	use super::*;
+
+	fn lookup<E>(name: &str, error: impl FnOnce(Error) -> E) -> Result<&'static Sqids, E> {
+		crate::registry::get(name).ok_or_else(|| error(Error::NotRegistered))
+	}
+
+	pub fn serialize<S: Serializer>(
+		name: &str,
+		number: &u64,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		let sqids = lookup(name, ::serde::ser::Error::custom)?;
+		serialize_numbers(sqids, &[*number], serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(
+		name: &str,
+		deserializer: D,
+	) -> Result<u64, D::Error> {
+		let sqids = lookup(name, de::Error::custom)?;
+		SqidsSeed::<u64>::new(sqids).deserialize(deserializer)
+	}
+
+	pub fn serialize_option<S: Serializer>(
+		name: &str,
+		number: &Option<u64>,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		let sqids = lookup(name, ::serde::ser::Error::custom)?;
+		number.as_ref().map(|number| SerializeWith::new(sqids, number)).serialize(serializer)
+	}
+
+	pub fn deserialize_option<'de, D: Deserializer<'de>>(
+		name: &str,
+		deserializer: D,
+	) -> Result<Option<u64>, D::Error> {
+		let sqids = lookup(name, de::Error::custom)?;
+		deserializer.deserialize_option(OptionVisitor(sqids))
+	}
+
+	pub fn serialize_vec<S: Serializer>(
+		name: &str,
+		numbers: &[u64],
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		let sqids = lookup(name, ::serde::ser::Error::custom)?;
+		serializer.collect_seq(numbers.iter().map(|number| SerializeWith::new(sqids, number)))
+	}
+
+	pub fn deserialize_vec<'de, D: Deserializer<'de>>(
+		name: &str,
+		deserializer: D,
+	) -> Result<Vec<u64>, D::Error> {
+		let sqids = lookup(name, de::Error::custom)?;
+		deserializer.deserialize_seq(SeqVisitor(sqids))
+	}
+
+	struct OptionVisitor(&'static Sqids);
+
+	impl<'de> de::Visitor<'de> for OptionVisitor {
+		type Value = Option<u64>;
+
+		fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			f.write_str("an optional sqid")
+		}
+
+		fn visit_none<E: de::Error>(self) -> Result<Option<u64>, E> {
+			Ok(None)
+		}
+
+		fn visit_some<D: Deserializer<'de>>(
+			self,
+			deserializer: D,
+		) -> Result<Option<u64>, D::Error> {
+			SqidsSeed::<u64>::new(self.0).deserialize(deserializer).map(Some)
+		}
+	}
+
+	struct SeqVisitor(&'static Sqids);
+
+	impl<'de> de::Visitor<'de> for SeqVisitor {
+		type Value = Vec<u64>;
+
+		fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			f.write_str("a sequence of sqids")
+		}
+
+		fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u64>, A::Error> {
+			let mut numbers = Vec::new();
+			while let Some(number) = seq.next_element_seed(SqidsSeed::<u64>::new(self.0))? {
+				numbers.push(number);
+			}
+			Ok(numbers)
+		}
+	}
+}
@@ -0,0 +1,94 @@
+//! [sqlx](https://docs.rs/sqlx) support for typed IDs.
+//!
+//! A [Sqid](struct@Sqid) binds and reads as an `INT8`, see
+//! [Integrations](struct@Sqid#integrations) for how it is stored. To keep the sqid itself in a text
+//! column instead, wrap the ID in [SqidText].
+
+use std::{
+	fmt::{self, Write},
+	str::FromStr,
+};
+
+use ::sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+use crate::{Error, Sqid};
+
+impl<T, DB: Database> Type<DB> for Sqid<T>
+where
+	i64: Type<DB>,
+{
+	fn type_info() -> DB::TypeInfo {
+		<i64 as Type<DB>>::type_info()
+	}
+
+	fn compatible(ty: &DB::TypeInfo) -> bool {
+		<i64 as Type<DB>>::compatible(ty)
+	}
+}
+
+impl<'q, T, DB: Database> Encode<'q, DB> for Sqid<T>
+where
+	i64: Encode<'q, DB>,
+{
+	fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+		let number = match self.numbers() {
+			[number] => i64::try_from(*number)
+				.map_err(|_| format!("sqid {self:?} does not fit in an INT8"))?,
+			_ => return Err(format!("sqid {self:?} is not a single number").into()),
+		};
+
+		number.encode_by_ref(buf)
+	}
+}
+
+impl<'r, T, DB: Database> Decode<'r, DB> for Sqid<T>
+where
+	i64: Decode<'r, DB>,
+{
+	fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+		let number = i64::decode(value)?;
+		Ok(Sqid::from(u64::try_from(number)?))
+	}
+}
+
+/// An ID stored as its sqid in a text column, and decoded when read.
+///
+/// `T` is a [Sqid](struct@Sqid) or another type whose `FromStr` only accepts canonical sqids, like the ones
+/// derived with `#[derive(Sqid)]`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SqidText<T>(pub T);
+
+impl<T, DB: Database> Type<DB> for SqidText<T>
+where
+	String: Type<DB>,
+{
+	fn type_info() -> DB::TypeInfo {
+		<String as Type<DB>>::type_info()
+	}
+
+	fn compatible(ty: &DB::TypeInfo) -> bool {
+		<String as Type<DB>>::compatible(ty)
+	}
+}
+
+impl<'q, T: fmt::Display, DB: Database> Encode<'q, DB> for SqidText<T>
+where
+	String: Encode<'q, DB>,
+{
+	fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+		// unlike `to_string`, does not panic when formatting fails
+		let mut id = String::new();
+		write!(id, "{}", self.0)?;
+		id.encode(buf)
+	}
+}
+
+impl<'r, T, DB: Database> Decode<'r, DB> for SqidText<T>
+where
+	T: FromStr<Err = Error>,
+	&'r str: Decode<'r, DB>,
+{
+	fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+		Ok(SqidText(<&str>::decode(value)?.parse()?))
+	}
+}
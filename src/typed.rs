@@ -0,0 +1,174 @@
+use std::{
+	cmp::Ordering,
+	fmt,
+	hash::{Hash, Hasher},
+	marker::PhantomData,
+	str::FromStr,
+};
+
+use crate::{Error, Result, Sqids};
+
+/// Associates a marker type with the [Sqids] used for its [Sqid]s.
+///
+/// ```
+/// # use std::sync::LazyLock;
+/// # use sqids::{Sqid, Sqids, SqidsConfig};
+/// struct User;
+///
+/// impl SqidsConfig for User {
+/// 	fn sqids() -> &'static Sqids {
+/// 		static SQIDS: LazyLock<Sqids> =
+/// 			LazyLock::new(|| Sqids::builder().min_length(8).build().unwrap());
+/// 		&SQIDS
+/// 	}
+/// }
+///
+/// let id = Sqid::<User>::from(1);
+/// assert_eq!(id.to_string(), "UkLWZg9D");
+/// assert_eq!("UkLWZg9D".parse::<Sqid<User>>()?, id);
+/// # Ok::<(), sqids::Error>(())
+/// ```
+pub trait SqidsConfig {
+	/// The [Sqids] used to encode and decode sqids of this type.
+	fn sqids() -> &'static Sqids;
+}
+
+/// Numbers identifying an entity of type `T`, rendered as a sqid.
+///
+/// Sqids of different entity types cannot be mixed up, since they are different types. Parsing
+/// only accepts canonical sqids (see [Sqids::decode_canonical]), so two [Sqid]s are equal exactly
+/// when their string representations are.
+///
+/// # Integrations
+///
+/// The web framework integrations (the `axum`, `actix` and `rocket` features) decode a [Sqid]
+/// from a request with the [Sqids] of `T`, like [FromStr] does, so that it renders back into the
+/// sqid of the request and non-canonical sqids are rejected.
+///
+/// The database integrations (the `diesel` and `sqlx` features) store a [Sqid] of a single number
+/// as that number in a 64-bit integer column, so the database sees numbers while Rust sees IDs
+/// that render as sqids. A [Sqid] of several numbers, or of a number above `i64::MAX`, fails to
+/// bind, and negative numbers fail to read.
+#[cfg_attr(
+	feature = "diesel",
+	derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::BigInt))]
+pub struct Sqid<T> {
+	numbers: Vec<u64>,
+	/// The number of a sqid of a single number up to `i64::MAX`, kept so that diesel can borrow
+	/// it for as long as the sqid when binding it.
+	#[cfg(feature = "diesel")]
+	pub(crate) big_int: Option<i64>,
+	marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Sqid<T> {
+	/// Create a [Sqid] from its numbers.
+	pub fn new(numbers: Vec<u64>) -> Self {
+		Sqid {
+			#[cfg(feature = "diesel")]
+			big_int: match numbers[..] {
+				[number] => i64::try_from(number).ok(),
+				_ => None,
+			},
+			numbers,
+			marker: PhantomData,
+		}
+	}
+
+	/// The numbers of this sqid.
+	pub fn numbers(&self) -> &[u64] {
+		&self.numbers
+	}
+
+	/// Convert into the numbers of this sqid.
+	pub fn into_numbers(self) -> Vec<u64> {
+		self.numbers
+	}
+}
+
+impl<T: SqidsConfig> Sqid<T> {
+	/// Generate the sqid with the [Sqids] of `T`, like `to_string` but returning
+	/// [Error::BlocklistMaxAttempts] instead of panicking.
+	pub fn encode(&self) -> Result<String> {
+		T::sqids().encode(&self.numbers)
+	}
+
+	/// Decode a canonical sqid with the [Sqids] of `T`.
+	pub fn decode(id: &str) -> Result<Self> {
+		match T::sqids().decode_canonical(id)? {
+			numbers if numbers.is_empty() => Err(Error::InvalidId),
+			numbers => Ok(Sqid::new(numbers)),
+		}
+	}
+}
+
+impl<T> From<u64> for Sqid<T> {
+	fn from(number: u64) -> Self {
+		Sqid::new(vec![number])
+	}
+}
+
+impl<T> From<Vec<u64>> for Sqid<T> {
+	fn from(numbers: Vec<u64>) -> Self {
+		Sqid::new(numbers)
+	}
+}
+
+/// Renders the sqid generated by [Sqid::encode].
+///
+/// Formatting fails when [Sqid::encode] does, which only happens when the blocklist blocks every
+/// sqid of the numbers ([Error::BlocklistMaxAttempts]), so `to_string` panics in that case. Use
+/// [Sqid::encode] where such a blocklist is possible.
+impl<T: SqidsConfig> fmt::Display for Sqid<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.encode().map_err(|_| fmt::Error)?)
+	}
+}
+
+impl<T: SqidsConfig> FromStr for Sqid<T> {
+	type Err = Error;
+
+	fn from_str(id: &str) -> Result<Self> {
+		Sqid::decode(id)
+	}
+}
+
+impl<T> fmt::Debug for Sqid<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("Sqid").field(&self.numbers).finish()
+	}
+}
+
+impl<T> Clone for Sqid<T> {
+	fn clone(&self) -> Self {
+		Sqid::new(self.numbers.clone())
+	}
+}
+
+impl<T> PartialEq for Sqid<T> {
+	fn eq(&self, other: &Self) -> bool {
+		self.numbers == other.numbers
+	}
+}
+
+impl<T> Eq for Sqid<T> {}
+
+impl<T> Hash for Sqid<T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.numbers.hash(state);
+	}
+}
+
+impl<T> PartialOrd for Sqid<T> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T> Ord for Sqid<T> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.numbers.cmp(&other.numbers)
+	}
+}
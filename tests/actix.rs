@@ -0,0 +1,95 @@
+#![cfg(feature = "actix")]
+
+use actix_web::{http::StatusCode, test, web, App};
+use sqids::{
+	actix::{SqidParam, SqidParamConfig},
+	Sqid, Sqids, SqidsConfig,
+};
+use std::sync::LazyLock;
+
+struct Order;
+
+impl SqidsConfig for Order {
+	fn sqids() -> &'static Sqids {
+		static SQIDS: LazyLock<Sqids> =
+			LazyLock::new(|| Sqids::builder().min_length(12).build().unwrap());
+		&SQIDS
+	}
+}
+
+async fn request(uri: &str) -> (StatusCode, String) {
+	let app = test::init_service(
+		App::new()
+			.app_data(web::Data::new(Sqids::builder().min_length(8).build().unwrap()))
+			.route(
+				"/numbers/{id}",
+				web::get().to(|SqidParam(id): SqidParam<u64>| async move { id.to_string() }),
+			)
+			.route(
+				"/tuples/{id}",
+				web::get().to(|SqidParam((a, b)): SqidParam<(u64, u64)>| async move {
+					format!("{a},{b}")
+				}),
+			)
+			.route(
+				"/orders/{id}",
+				web::get()
+					.to(|SqidParam(id): SqidParam<Sqid<Order>>| async move { id.to_string() }),
+			)
+			.route(
+				"/items/{item}",
+				web::get().to(|SqidParam(id): SqidParam<u64>| async move { id.to_string() }),
+			)
+			.service(
+				web::resource("/query").app_data(SqidParamConfig::default().name("item")).route(
+					web::get().to(|SqidParam(id): SqidParam<u64>| async move { id.to_string() }),
+				),
+			),
+	)
+	.await;
+
+	let response = test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await;
+	let status = response.status();
+	let body = test::read_body(response).await;
+	(status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[actix_rt::test]
+async fn path() {
+	assert_eq!(request("/numbers/UkLWZg9D").await, (StatusCode::OK, "1".to_string()));
+
+	let id = Sqids::builder().min_length(8).build().unwrap().encode(&[1, 2]).unwrap();
+	assert_eq!(request(&format!("/tuples/{id}")).await, (StatusCode::OK, "1,2".to_string()));
+}
+
+#[actix_rt::test]
+async fn query() {
+	assert_eq!(request("/query?item=UkLWZg9D").await, (StatusCode::OK, "1".to_string()));
+	assert_eq!(request("/query?id=UkLWZg9D").await.0, StatusCode::BAD_REQUEST);
+}
+
+#[actix_rt::test]
+async fn typed() {
+	// decoded with the sqids of `Order`, not the app data
+	let id = Sqid::<Order>::new(vec![1, 2, 3]).to_string();
+	assert_eq!(id.len(), 12);
+
+	assert_eq!(request(&format!("/orders/{id}")).await, (StatusCode::OK, id));
+	assert_eq!(request("/orders/86Rf07xd").await.0, StatusCode::NOT_FOUND);
+}
+
+#[actix_rt::test]
+async fn missing() {
+	// the route's parameter is not named `id` and there is no config
+	assert_eq!(request("/items/UkLWZg9D").await.0, StatusCode::BAD_REQUEST);
+}
+
+#[actix_rt::test]
+async fn rejected() {
+	// not canonical, invalid characters and wrong number of numbers
+	assert_eq!(request("/numbers/Uk").await.0, StatusCode::NOT_FOUND);
+	assert_eq!(request("/numbers/Uk-LWZg9D").await.0, StatusCode::NOT_FOUND);
+	assert_eq!(request("/numbers/86Rf07xd").await.0, StatusCode::NOT_FOUND);
+	assert_eq!(request("/tuples/UkLWZg9D").await.0, StatusCode::NOT_FOUND);
+	assert_eq!(request("/query?item=Uk").await.0, StatusCode::NOT_FOUND);
+}
@@ -1,4 +1,5 @@
 use sqids::*;
+use std::collections::HashSet;
 
 #[test]
 fn simple() {
@@ -55,3 +56,104 @@ fn too_short_alphabet() {
 		Error::AlphabetLength
 	)
 }
+
+#[test]
+fn alphabet_from_seed() {
+	let sqids = Sqids::builder().alphabet_from_seed(b"secret").build().unwrap();
+	let same = Sqids::builder().alphabet_from_seed(b"secret").build().unwrap();
+	let other = Sqids::builder().alphabet_from_seed(b"rotated").build().unwrap();
+
+	let numbers = vec![1, 2, 3];
+	let id = sqids.encode(&numbers).unwrap();
+
+	assert_eq!(same.encode(&numbers).unwrap(), id);
+	assert_ne!(other.encode(&numbers).unwrap(), id);
+	assert_eq!(sqids.decode(&id), numbers);
+}
+
+#[test]
+fn alphabet_from_seed_uses_custom_base() {
+	let sqids = Sqids::builder()
+		.alphabet("0123456789abcdef".chars().collect())
+		.alphabet_from_seed(b"secret")
+		.build()
+		.unwrap();
+
+	let numbers = vec![1, 2, 3];
+	let id = sqids.encode(&numbers).unwrap();
+
+	assert!(id.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+	assert_eq!(sqids.decode(&id), numbers);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_alphabet() {
+	let alphabet = sqids::random_alphabet();
+
+	let mut sorted: Vec<char> = alphabet.chars().collect();
+	sorted.sort_unstable();
+	let mut expected: Vec<char> = DEFAULT_ALPHABET.chars().collect();
+	expected.sort_unstable();
+	assert_eq!(sorted, expected);
+
+	let sqids = Sqids::builder().alphabet(alphabet.chars().collect()).build().unwrap();
+	assert_eq!(sqids.decode(&sqids.encode(&[1, 2, 3]).unwrap()), vec![1, 2, 3]);
+}
+
+#[test]
+fn presets() {
+	for preset in Alphabet::ALL {
+		let sqids = Sqids::builder().alphabet(preset.into()).build().unwrap();
+
+		let numbers = vec![0, 1, 2, 3, 1_000_000, u64::MAX];
+		let id = sqids.encode(&numbers).unwrap();
+
+		assert!(id.chars().all(|c| preset.as_str().contains(c)), "{preset:?}: {id}");
+		assert_eq!(sqids.decode(&id), numbers);
+	}
+}
+
+#[test]
+fn presets_filter_default_blocklist() {
+	let blocklist: Vec<String> =
+		default_blocklist().into_iter().map(|w| w.to_lowercase()).collect();
+
+	for preset in Alphabet::ALL {
+		let sqids = Sqids::builder().alphabet(preset.into()).build().unwrap();
+		let unfiltered =
+			Sqids::builder().alphabet(preset.into()).blocklist(HashSet::new()).build().unwrap();
+		let lowercase_alphabet = preset.as_str().to_lowercase();
+		let words: Vec<&str> = blocklist
+			.iter()
+			.filter(|w| w.len() >= 3 && w.chars().all(|c| lowercase_alphabet.contains(c)))
+			.map(|w| w.as_str())
+			.collect();
+		let is_blocked = |id: &str| {
+			let id = id.to_lowercase();
+			words.iter().any(|word| {
+				if id.len() <= 3 || word.len() <= 3 {
+					id == *word
+				} else if word.chars().any(|c| c.is_ascii_digit()) {
+					id.starts_with(word) || id.ends_with(word)
+				} else {
+					id.contains(word)
+				}
+			})
+		};
+
+		// the first number whose unfiltered id is blocked for each preset
+		let n = match preset {
+			Alphabet::Lowercase => 75_100,
+			Alphabet::Crockford32 => 45_019,
+			Alphabet::Unambiguous => 8_620,
+			Alphabet::QrAlphanumeric => 14_768,
+			Alphabet::UrlSafe64 => 50_290,
+		};
+		assert!(is_blocked(&unfiltered.encode(&[n]).unwrap()), "{preset:?}");
+
+		let id = sqids.encode(&[n]).unwrap();
+		assert!(!is_blocked(&id), "{preset:?}: {id} is blocked");
+		assert_eq!(sqids.decode(&id), vec![n]);
+	}
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "arrow")]
+
+use arrow_array::{
+	types::{Int64Type, UInt64Type},
+	Array, ListArray, StringArray, UInt64Array,
+};
+use sqids::{arrow, Sqids};
+
+#[test]
+fn encode() {
+	let sqids = Sqids::builder().min_length(8).build().unwrap();
+
+	let ids =
+		arrow::encode_array(&sqids, &UInt64Array::from(vec![Some(1), None, Some(2)])).unwrap();
+	assert_eq!(
+		ids,
+		StringArray::from(vec![Some("UkLWZg9D"), None, Some(sqids.encode(&[2]).unwrap().as_str())])
+	);
+}
+
+#[test]
+fn encode_list() {
+	let sqids = Sqids::builder().min_length(8).build().unwrap();
+
+	let rows = ListArray::from_iter_primitive::<UInt64Type, _, _>([
+		Some(vec![Some(1), Some(2), Some(3)]),
+		None,
+		Some(vec![Some(1)]),
+	]);
+	let ids = arrow::encode_list_array(&sqids, &rows).unwrap();
+	assert_eq!(ids, StringArray::from(vec![Some("86Rf07xd"), None, Some("UkLWZg9D")]));
+
+	// a slice of a list array keeps the offsets of the whole array
+	let ids = arrow::encode_list_array(&sqids, &rows.slice(2, 1)).unwrap();
+	assert_eq!(ids, StringArray::from(vec!["UkLWZg9D"]));
+}
+
+#[test]
+fn encode_list_errors() {
+	let sqids = Sqids::default();
+
+	let signed = ListArray::from_iter_primitive::<Int64Type, _, _>([Some(vec![Some(1)])]);
+	assert!(arrow::encode_list_array(&sqids, &signed).is_err());
+
+	let nulls = ListArray::from_iter_primitive::<UInt64Type, _, _>([Some(vec![Some(1), None])]);
+	assert!(arrow::encode_list_array(&sqids, &nulls).is_err());
+}
+
+#[test]
+fn decode() {
+	let sqids = Sqids::builder().min_length(8).build().unwrap();
+
+	let numbers = arrow::decode_array(
+		&sqids,
+		&StringArray::from(vec![Some("86Rf07xd"), None, Some("UkLWZg9D")]),
+	)
+	.unwrap();
+	let expected = ListArray::from_iter_primitive::<UInt64Type, _, _>([
+		Some(vec![Some(1), Some(2), Some(3)]),
+		None,
+		Some(vec![Some(1)]),
+	]);
+	assert_eq!(numbers, expected);
+
+	// not canonical
+	assert!(arrow::decode_array(&sqids, &StringArray::from(vec!["Uk"])).is_err());
+}
+
+#[test]
+fn roundtrip() {
+	let sqids = Sqids::default();
+	let numbers = UInt64Array::from_iter_values(0..1_000);
+
+	let ids = arrow::encode_array(&sqids, &numbers).unwrap();
+	let decoded = arrow::decode_array(&sqids, &ids).unwrap();
+
+	assert_eq!(decoded.len(), 1_000);
+	assert_eq!(decoded.values().as_ref(), &numbers as &dyn Array);
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "axum")]
+
+use axum::{
+	body::Body,
+	http::{Request, StatusCode},
+	response::{IntoResponse, Response},
+	routing::get,
+	Router,
+};
+use http_body_util::BodyExt;
+use sqids::{
+	axum::{SqidPath, SqidRejection},
+	Sqid, Sqids, SqidsConfig,
+};
+use std::sync::{Arc, LazyLock};
+use tower::ServiceExt;
+
+struct Order;
+
+impl SqidsConfig for Order {
+	fn sqids() -> &'static Sqids {
+		static SQIDS: LazyLock<Sqids> =
+			LazyLock::new(|| Sqids::builder().min_length(12).build().unwrap());
+		&SQIDS
+	}
+}
+
+struct ApiError;
+
+impl From<SqidRejection> for ApiError {
+	fn from(_: SqidRejection) -> Self {
+		ApiError
+	}
+}
+
+impl IntoResponse for ApiError {
+	fn into_response(self) -> Response {
+		(StatusCode::BAD_REQUEST, "bad id").into_response()
+	}
+}
+
+fn app() -> Router {
+	Router::new()
+		.route("/numbers/{id}", get(|id: SqidPath<u64>| async move { id.to_string() }))
+		.route(
+			"/tuples/{id}",
+			get(|id: SqidPath<(u64, u64)>| async move {
+				let (a, b) = id.into_inner();
+				format!("{a},{b}")
+			}),
+		)
+		.route("/orders/{id}", get(|id: SqidPath<Sqid<Order>>| async move { id.to_string() }))
+		.route("/custom/{id}", get(|id: SqidPath<u64, ApiError>| async move { id.to_string() }))
+		.with_state(Arc::new(Sqids::builder().min_length(8).build().unwrap()))
+}
+
+async fn request(uri: &str) -> (StatusCode, String) {
+	let response = app().oneshot(Request::get(uri).body(Body::empty()).unwrap()).await.unwrap();
+	let status = response.status();
+	let body = response.into_body().collect().await.unwrap().to_bytes();
+	(status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn number() {
+	assert_eq!(request("/numbers/UkLWZg9D").await, (StatusCode::OK, "1".to_string()));
+}
+
+#[tokio::test]
+async fn tuple() {
+	let id = Sqids::builder().min_length(8).build().unwrap().encode(&[1, 2]).unwrap();
+
+	assert_eq!(request(&format!("/tuples/{id}")).await, (StatusCode::OK, "1,2".to_string()));
+}
+
+#[tokio::test]
+async fn typed() {
+	// decoded with the sqids of `Order`, not the ones of the router
+	let id = Sqid::<Order>::new(vec![1, 2, 3]).to_string();
+	assert_eq!(id.len(), 12);
+
+	assert_eq!(request(&format!("/orders/{id}")).await, (StatusCode::OK, id));
+	assert_eq!(request("/orders/86Rf07xd").await.0, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn rejected() {
+	// not canonical, invalid characters and wrong number of numbers
+	assert_eq!(request("/numbers/Uk").await.0, StatusCode::NOT_FOUND);
+	assert_eq!(request("/numbers/Uk-LWZg9D").await.0, StatusCode::NOT_FOUND);
+	assert_eq!(request("/numbers/86Rf07xd").await.0, StatusCode::NOT_FOUND);
+	assert_eq!(request("/tuples/UkLWZg9D").await.0, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn custom_rejection() {
+	assert_eq!(request("/custom/UkLWZg9D").await, (StatusCode::OK, "1".to_string()));
+	assert_eq!(request("/custom/Uk").await, (StatusCode::BAD_REQUEST, "bad id".to_string()));
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "rayon")]
+
+use sqids::{Error, Sqids};
+
+#[test]
+fn encode_batch() {
+	let sqids = Sqids::builder().min_length(8).build().unwrap();
+	let numbers: Vec<Vec<u64>> = (0..1_000).map(|i| vec![i, i * 2, i * 3]).collect();
+	let batch: Vec<&[u64]> = numbers.iter().map(Vec::as_slice).collect();
+
+	let ids = sqids.encode_batch(&batch);
+
+	assert_eq!(ids.len(), 1_000);
+	for (numbers, id) in batch.iter().zip(ids) {
+		assert_eq!(id, sqids.encode(numbers));
+	}
+}
+
+#[test]
+fn encode_each() {
+	let sqids = Sqids::default();
+	let numbers: Vec<u64> = (0..1_000).collect();
+
+	let ids = sqids.encode_each(&numbers);
+
+	for (number, id) in numbers.iter().zip(ids) {
+		assert_eq!(sqids.decode(&id.unwrap()), [*number]);
+	}
+}
+
+#[test]
+fn decode_batch() {
+	let sqids = Sqids::default();
+	let ids: Vec<String> = (0..1_000).map(|i| sqids.encode(&[i]).unwrap()).collect();
+	let mut ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+	ids[500] = "!";
+
+	let numbers = sqids.decode_batch(&ids);
+
+	for (i, numbers) in numbers.into_iter().enumerate() {
+		match i {
+			500 => assert_eq!(numbers, Err(Error::InvalidId)),
+			_ => assert_eq!(numbers, Ok(vec![i as u64])),
+		}
+	}
+}
+
+#[test]
+fn errors() {
+	let sqids = Sqids::builder()
+		.alphabet("abc".chars().collect())
+		.min_length(3)
+		.blocklist(["aac".to_string(), "bba".to_string(), "ccb".to_string()].into())
+		.build()
+		.unwrap();
+
+	let ids = sqids.encode_batch(&[&[1], &[], &[2]]);
+
+	assert_eq!(ids[0], Err(Error::BlocklistMaxAttempts));
+	assert_eq!(ids[1], Ok(String::new()));
+	assert_eq!(ids[2], sqids.encode(&[2]));
+}
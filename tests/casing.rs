@@ -0,0 +1,131 @@
+use sqids::*;
+
+#[test]
+fn case_insensitive() {
+	let sqids = Sqids::builder()
+		.alphabet(Alphabet::Lowercase.into())
+		.case_insensitive(true)
+		.build()
+		.unwrap();
+
+	let numbers = vec![1, 2, 3];
+	let id = sqids.encode(&numbers).unwrap();
+
+	assert_eq!(sqids.decode(&id), numbers);
+	assert_eq!(sqids.decode(&id.to_uppercase()), numbers);
+}
+
+#[test]
+fn case_insensitive_uppercase_alphabet() {
+	let sqids = Sqids::builder()
+		.alphabet(Alphabet::Crockford32.into())
+		.case_insensitive(true)
+		.build()
+		.unwrap();
+
+	let numbers = vec![1_000_000, u64::MAX];
+	let id = sqids.encode(&numbers).unwrap();
+
+	assert_eq!(sqids.decode(&id.to_lowercase()), numbers);
+}
+
+#[test]
+fn case_sensitive_by_default() {
+	let sqids = Sqids::builder().alphabet(Alphabet::Lowercase.into()).build().unwrap();
+
+	let id = sqids.encode(&[1, 2, 3]).unwrap();
+
+	assert_eq!(sqids.decode(&id.to_uppercase()), Vec::<u64>::new());
+}
+
+#[test]
+fn case_collision() {
+	assert_eq!(
+		Sqids::builder()
+			.alphabet("abcA".chars().collect())
+			.case_insensitive(true)
+			.build()
+			.unwrap_err(),
+		Error::AlphabetCaseCollision
+	);
+}
+
+#[test]
+fn confusables() {
+	let sqids = Sqids::builder()
+		.alphabet(Alphabet::Unambiguous.into())
+		.confusables(&[('0', 'Q'), ('O', 'Q'), ('1', 'L'), ('I', 'L'), ('l', 'L')])
+		.build()
+		.unwrap();
+
+	let numbers = vec![1, 2, 3, 1_000_000];
+	let id = sqids.encode(&numbers).unwrap();
+
+	assert_eq!(sqids.decode(&id.replace('Q', "0").replace('L', "l")), numbers);
+	assert_eq!(sqids.decode(&id.replace('Q', "O").replace('L', "I")), numbers);
+}
+
+#[test]
+fn confusables_case_insensitive() {
+	let sqids = Sqids::builder()
+		.alphabet(Alphabet::Crockford32.into())
+		.case_insensitive(true)
+		.confusables(&[('O', '0'), ('I', '1'), ('L', '1'), ('U', 'V')])
+		.build()
+		.unwrap();
+
+	let numbers = vec![1, 2, 3];
+	let id = sqids.encode(&numbers).unwrap();
+	let mistyped = id.replace('0', "o").replace('1', "l").to_lowercase();
+
+	assert_eq!(sqids.decode(&mistyped), numbers);
+}
+
+#[test]
+fn confusable_source_in_alphabet() {
+	assert_eq!(
+		Sqids::builder().confusables(&[('a', 'b')]).build().unwrap_err(),
+		Error::ConfusableSource
+	);
+}
+
+#[test]
+fn confusable_source_folds_into_alphabet() {
+	assert_eq!(
+		Sqids::builder()
+			.alphabet(Alphabet::Lowercase.into())
+			.case_insensitive(true)
+			.confusables(&[('O', '0')])
+			.build()
+			.unwrap_err(),
+		Error::ConfusableSource
+	);
+	assert_eq!(
+		Sqids::builder()
+			.alphabet(Alphabet::Crockford32.into())
+			.case_insensitive(true)
+			.confusables(&[('b', '8')])
+			.build()
+			.unwrap_err(),
+		Error::ConfusableSource
+	);
+
+	// case-sensitive sqids never fold `O` into `o`
+	assert!(Sqids::builder()
+		.alphabet(Alphabet::Lowercase.into())
+		.confusables(&[('O', 'o')])
+		.build()
+		.is_ok());
+}
+
+#[test]
+fn confusable_target_not_in_alphabet() {
+	assert_eq!(
+		Sqids::builder()
+			.alphabet(Alphabet::Lowercase.into())
+			.confusables(&[('O', 'Z')])
+			.build()
+			.unwrap_err(),
+		Error::ConfusableTarget
+	);
+}
@@ -0,0 +1,82 @@
+use sqids::*;
+
+#[test]
+fn simple() {
+	let sqids = Sqids::builder().check_character(true).build().unwrap();
+
+	let numbers = vec![1, 2, 3];
+	let id = sqids.encode(&numbers).unwrap();
+
+	assert_eq!(id.len(), Sqids::default().encode(&numbers).unwrap().len() + 1);
+	assert_eq!(sqids.decode(&id), numbers);
+	assert_eq!(sqids.try_decode(&id), Ok(numbers));
+}
+
+#[test]
+fn min_length_includes_check_character() {
+	let sqids = Sqids::builder().check_character(true).min_length(10).build().unwrap();
+
+	let numbers = vec![1, 2, 3];
+	let id = sqids.encode(&numbers).unwrap();
+
+	assert_eq!(id.len(), 10);
+	assert_eq!(sqids.decode(&id), numbers);
+}
+
+#[test]
+fn substitutions() {
+	let sqids = Sqids::builder().check_character(true).build().unwrap();
+
+	for n in 0..20 {
+		let id: Vec<char> = sqids.encode(&[n, n * 1_000]).unwrap().chars().collect();
+
+		for i in 0..id.len() {
+			for c in DEFAULT_ALPHABET.chars().filter(|&c| c != id[i]) {
+				let mut mistyped = id.clone();
+				mistyped[i] = c;
+				let mistyped: String = mistyped.into_iter().collect();
+
+				assert_eq!(sqids.try_decode(&mistyped), Err(Error::CheckCharacterMismatch));
+				assert_eq!(sqids.decode(&mistyped), Vec::<u64>::new());
+			}
+		}
+	}
+}
+
+#[test]
+fn transpositions() {
+	let sqids = Sqids::builder().check_character(true).build().unwrap();
+
+	let mut total = 0;
+	let mut undetected = 0;
+	for n in 0..1_000 {
+		let id: Vec<char> = sqids.encode(&[n, n * 1_000]).unwrap().chars().collect();
+
+		for i in 0..id.len() - 1 {
+			if id[i] == id[i + 1] {
+				continue;
+			}
+
+			let mut mistyped = id.clone();
+			mistyped.swap(i, i + 1);
+			let mistyped: String = mistyped.into_iter().collect();
+
+			total += 1;
+			if sqids.try_decode(&mistyped) != Err(Error::CheckCharacterMismatch) {
+				undetected += 1;
+			}
+		}
+	}
+
+	// only the transposition of the first and last alphabet characters goes undetected
+	assert!(undetected * 100 < total, "{undetected} of {total} transpositions undetected");
+}
+
+#[test]
+fn invalid_ids() {
+	let sqids = Sqids::builder().check_character(true).build().unwrap();
+
+	assert_eq!(sqids.try_decode(""), Ok(vec![]));
+	assert_eq!(sqids.try_decode("a"), Err(Error::InvalidId));
+	assert_eq!(sqids.try_decode("a*"), Err(Error::InvalidId));
+}
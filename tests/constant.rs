@@ -0,0 +1,71 @@
+use sqids::*;
+use std::collections::HashSet;
+
+const SQIDS: ConstSqids<62> = ConstSqids::new(DEFAULT_ALPHABET_BYTES);
+const SYSTEM_USER: ConstSqid<16> = SQIDS.encode(&[1, 2, 3]);
+const BLOCKED: ConstSqid<16> = SQIDS.encode(&[4572721]);
+const PADDED: ConstSqid<64> = SQIDS.min_length(10).encode(&[1, 2, 3]);
+
+#[test]
+fn simple() {
+	assert_eq!(SYSTEM_USER.as_str(), "86Rf07");
+	assert_eq!(BLOCKED.as_str(), "JExTR");
+	assert_eq!(PADDED.as_str(), "86Rf07xd4z");
+	assert_eq!(SYSTEM_USER.to_string(), "86Rf07");
+}
+
+#[test]
+fn matches_sqids() {
+	let sqids = Sqids::default();
+
+	for numbers in [
+		vec![],
+		vec![0],
+		vec![u64::MAX],
+		vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+		vec![0, 0, 0, 1, 2, 3, 100, 1_000, 100_000, 1_000_000, u64::MAX],
+	] {
+		assert_eq!(&*SQIDS.encode::<128>(&numbers), sqids.encode(&numbers).unwrap());
+	}
+
+	for n in 0..10_000 {
+		assert_eq!(&*SQIDS.encode::<16>(&[n]), sqids.encode(&[n]).unwrap());
+	}
+}
+
+#[test]
+fn matches_sqids_with_options() {
+	let alphabet = b"abcDEF012";
+	let blocklist = &["dead", "beef", "0fa"];
+
+	let const_sqids = ConstSqids::new(alphabet).min_length(5).blocklist(blocklist);
+	let sqids = Sqids::builder()
+		.alphabet(alphabet.iter().map(|&c| c as char).collect())
+		.min_length(5)
+		.blocklist(blocklist.iter().map(|w| w.to_string()).collect::<HashSet<_>>())
+		.build()
+		.unwrap();
+
+	for n in 0..10_000 {
+		assert_eq!(&*const_sqids.encode::<32>(&[n, n / 7]), sqids.encode(&[n, n / 7]).unwrap());
+	}
+}
+
+#[test]
+#[should_panic(expected = "capacity")]
+fn capacity() {
+	SQIDS.encode::<4>(&[1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "unique")]
+fn repeating_alphabet_characters() {
+	ConstSqids::new(b"aabcdefg");
+}
+
+const SYSTEM_USER_STR: &str = SYSTEM_USER.as_str();
+
+#[test]
+fn const_str() {
+	assert_eq!(SYSTEM_USER_STR, "86Rf07");
+}
@@ -0,0 +1,85 @@
+use sqids::*;
+
+#[test]
+fn substitution() {
+	let sqids = Sqids::builder().check_character(true).build().unwrap();
+
+	let id = sqids.encode(&[1, 2, 3]).unwrap();
+	let mistyped = format!("{}{}", if id.starts_with('a') { 'b' } else { 'a' }, &id[1..]);
+
+	assert!(sqids.decode(&mistyped).is_empty());
+	assert!(sqids.suggest_corrections(&mistyped, 1).contains(&id));
+}
+
+#[test]
+fn transposition() {
+	let sqids = Sqids::builder().check_character(true).build().unwrap();
+
+	let id = sqids.encode(&[1_000_000]).unwrap();
+	let mut mistyped: Vec<char> = id.chars().collect();
+	mistyped.swap(1, 2);
+	let mistyped: String = mistyped.into_iter().collect();
+
+	assert!(sqids.suggest_corrections(&mistyped, 1).contains(&id));
+}
+
+#[test]
+fn insertion_and_deletion() {
+	let sqids = Sqids::default();
+
+	let id = sqids.encode(&[1, 2, 3]).unwrap();
+
+	assert!(sqids.suggest_corrections(&id[1..], 1).contains(&id));
+	assert!(sqids.suggest_corrections(&format!("{id}a"), 1).contains(&id));
+}
+
+#[test]
+fn suggestions_are_canonical_and_ranked() {
+	let sqids = Sqids::builder().alphabet("0123456789".chars().collect()).build().unwrap();
+
+	let id = sqids.encode(&[1, 2, 3]).unwrap();
+	let one_edit = sqids.suggest_corrections(&id, 1);
+	let two_edits = sqids.suggest_corrections(&id, 2);
+
+	assert!(!one_edit.contains(&id));
+	assert_eq!(two_edits[..one_edit.len()], one_edit[..]);
+	for suggestion in &two_edits {
+		assert_eq!(sqids.encode(&sqids.decode(suggestion)).unwrap(), *suggestion);
+	}
+}
+
+#[test]
+fn no_edits() {
+	let sqids = Sqids::default();
+
+	assert_eq!(sqids.suggest_corrections("86Rf07", 0), Vec::<String>::new());
+}
+
+#[test]
+fn one_insertion_or_deletion() {
+	let sqids = Sqids::default();
+
+	let id = sqids.encode(&[1, 2, 3]).unwrap();
+
+	assert!(!sqids.suggest_corrections(&id[2..], 2).contains(&id));
+	assert!(!sqids.suggest_corrections(&format!("{id}ab"), 2).contains(&id));
+}
+
+#[test]
+fn limits() {
+	let sqids = Sqids::default();
+
+	assert_eq!(sqids.suggest_corrections("86Rf0x", 2).len(), MAX_SUGGESTIONS);
+	assert_eq!(sqids.suggest_corrections("86Rf0x", MAX_SUGGESTION_EDITS + 1), Vec::<String>::new());
+}
+
+#[test]
+fn long_ids() {
+	let sqids = Sqids::builder().min_length(MAX_SUGGESTION_ID_LEN as u8).build().unwrap();
+
+	let id = sqids.encode(&[1]).unwrap();
+	assert_eq!(id.len(), MAX_SUGGESTION_ID_LEN);
+	assert!(sqids.suggest_corrections(&id[1..], 1).contains(&id));
+	assert_eq!(sqids.suggest_corrections(&format!("{id}a"), 1), Vec::<String>::new());
+	assert_eq!(sqids.suggest_corrections(&"a".repeat(10_000), 2), Vec::<String>::new());
+}
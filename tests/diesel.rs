@@ -0,0 +1,81 @@
+#![cfg(feature = "diesel")]
+
+use diesel::{prelude::*, sql_query, sqlite::SqliteConnection};
+use sqids::{Sqid, Sqids, SqidsConfig};
+use std::sync::LazyLock;
+
+diesel::table! {
+	orders (id) {
+		id -> BigInt,
+		parent_id -> Nullable<BigInt>,
+	}
+}
+
+struct Order;
+
+impl SqidsConfig for Order {
+	fn sqids() -> &'static Sqids {
+		static SQIDS: LazyLock<Sqids> =
+			LazyLock::new(|| Sqids::builder().min_length(8).build().unwrap());
+		&SQIDS
+	}
+}
+
+#[derive(Debug, PartialEq, Queryable, Insertable)]
+#[diesel(table_name = orders)]
+struct Row {
+	id: Sqid<Order>,
+	parent_id: Option<Sqid<Order>>,
+}
+
+fn connection() -> SqliteConnection {
+	let mut connection = SqliteConnection::establish(":memory:").unwrap();
+	sql_query("CREATE TABLE orders (id BIGINT PRIMARY KEY NOT NULL, parent_id BIGINT)")
+		.execute(&mut connection)
+		.unwrap();
+	connection
+}
+
+#[test]
+fn roundtrip() {
+	let mut connection = connection();
+	let row = Row { id: "UkLWZg9D".parse().unwrap(), parent_id: None };
+
+	diesel::insert_into(orders::table).values(&row).execute(&mut connection).unwrap();
+
+	let rows: Vec<Row> = orders::table.load(&mut connection).unwrap();
+	assert_eq!(rows, [row]);
+	assert_eq!(rows[0].id.to_string(), "UkLWZg9D");
+}
+
+#[test]
+fn numbers_in_database() {
+	let mut connection = connection();
+	let row = Row { id: Sqid::from(2), parent_id: Some(Sqid::from(1)) };
+
+	diesel::insert_into(orders::table).values(&row).execute(&mut connection).unwrap();
+
+	let numbers: (i64, Option<i64>) =
+		orders::table.select((orders::id, orders::parent_id)).first(&mut connection).unwrap();
+	assert_eq!(numbers, (2, Some(1)));
+
+	let found: Row = orders::table
+		.filter(orders::parent_id.eq(Sqid::<Order>::from(1)))
+		.first(&mut connection)
+		.unwrap();
+	assert_eq!(found, row);
+}
+
+#[test]
+fn unrepresentable() {
+	let mut connection = connection();
+
+	for id in [Sqid::from(vec![1, 2]), Sqid::from(u64::MAX)] {
+		let row = Row { id, parent_id: None };
+		assert!(diesel::insert_into(orders::table).values(&row).execute(&mut connection).is_err());
+	}
+
+	// negative numbers are not valid sqid numbers
+	sql_query("INSERT INTO orders (id) VALUES (-1)").execute(&mut connection).unwrap();
+	assert!(orders::table.load::<Row>(&mut connection).is_err());
+}
@@ -132,3 +132,60 @@ fn decoding_number_overflows() {
 	let numbers = sqids.decode("0J4AEXRN106Z0"); // `https://github.com/sqids/sqids-rust/pull/7`
 	assert_eq!(numbers, Vec::<u64>::new());
 }
+
+#[test]
+fn encode_iter() {
+	let sqids = Sqids::builder().min_length(10).build().unwrap();
+
+	let numbers = vec![0, 1, 2, 3, 100, 1_000_000, u64::MAX];
+	assert_eq!(
+		sqids.encode_iter(numbers.iter().copied()).unwrap(),
+		sqids.encode(&numbers).unwrap()
+	);
+	assert_eq!(sqids.encode_iter([1, 2, 3]).unwrap(), sqids.encode(&[1, 2, 3]).unwrap());
+	assert_eq!(sqids.encode_iter(numbers.iter().map(|n| n / 2)).unwrap(), {
+		let halves: Vec<u64> = numbers.iter().map(|n| n / 2).collect();
+		sqids.encode(&halves).unwrap()
+	});
+	assert_eq!(sqids.encode_iter([]).unwrap(), "");
+}
+
+#[test]
+fn encode_iter_blocklist() {
+	// the numbers are read again for each blocklist attempt
+	let sqids = Sqids::builder().blocklist(["86Rf07".to_string()].into()).build().unwrap();
+
+	assert_eq!(sqids.encode_iter([1, 2, 3]).unwrap(), sqids.encode(&[1, 2, 3]).unwrap());
+	assert_ne!(sqids.encode_iter([1, 2, 3]).unwrap(), "86Rf07");
+}
+
+#[test]
+fn decode_iter() {
+	let sqids = Sqids::default();
+
+	let numbers = vec![0, 0, 0, 1, 2, 3, 100, 1_000, 100_000, 1_000_000, u64::MAX];
+	let id = sqids.encode(&numbers).unwrap();
+	assert_eq!(sqids.decode_iter(&id).collect::<Result<Vec<_>>>(), Ok(numbers));
+
+	let mut numbers = sqids.decode_iter("86Rf07");
+	assert_eq!(numbers.next(), Some(Ok(1)));
+	assert_eq!(numbers.next(), Some(Ok(2)));
+	assert_eq!(numbers.next(), Some(Ok(3)));
+	assert_eq!(numbers.next(), None);
+	assert_eq!(numbers.next(), None);
+}
+
+#[test]
+fn decode_iter_matches_try_decode() {
+	let sqids = Sqids::builder().check_character(true).build().unwrap();
+	let id = sqids.encode(&[1, 2, 3]).unwrap();
+
+	for id in ["", "*", "ABARpJzdz9", "0J4AEXRN106Z0", "86Rf07", id.as_str(), &id[..id.len() - 1]] {
+		assert_eq!(sqids.decode_iter(id).collect::<Result<Vec<_>>>(), sqids.try_decode(id), "{id}");
+	}
+
+	let sqids = Sqids::default();
+	for id in ["", "*", "ABARpJzdz9", "0J4AEXRN106Z0", "86Rf07"] {
+		assert_eq!(sqids.decode_iter(id).collect::<Result<Vec<_>>>(), sqids.try_decode(id), "{id}");
+	}
+}
@@ -0,0 +1,53 @@
+use sqids::*;
+
+#[test]
+fn simple() {
+	let sqids = Sqids::builder().group(4, '-').min_length(10).build().unwrap();
+
+	let numbers = vec![1, 2, 3];
+	let id = "86Rf-07xd-4z";
+
+	assert_eq!(sqids.encode(&numbers).unwrap(), id);
+	assert_eq!(sqids.decode(id), numbers);
+}
+
+#[test]
+fn grouped_and_ungrouped_decode_identically() {
+	let sqids = Sqids::builder().group(3, '-').build().unwrap();
+	let ungrouped = Sqids::default();
+
+	for numbers in [vec![0], vec![1, 2, 3], vec![1_000_000, u64::MAX]] {
+		let id = sqids.encode(&numbers).unwrap();
+		let plain = ungrouped.encode(&numbers).unwrap();
+
+		assert_eq!(id.replace('-', ""), plain);
+		assert_eq!(sqids.decode(&id), numbers);
+		assert_eq!(sqids.decode(&plain), numbers);
+		assert_eq!(sqids.decode(&id.replace('-', " ")), numbers);
+		assert_eq!(sqids.decode(&format!(" {id}\n")), numbers);
+	}
+}
+
+#[test]
+fn short_ids_are_not_grouped() {
+	let sqids = Sqids::builder().group(4, '-').build().unwrap();
+
+	assert_eq!(sqids.encode(&[0]).unwrap(), "bM");
+}
+
+#[test]
+fn with_check_character() {
+	let sqids = Sqids::builder().group(4, '-').check_character(true).build().unwrap();
+
+	let numbers = vec![1, 2, 3];
+	let id = sqids.encode(&numbers).unwrap();
+
+	assert_eq!(sqids.try_decode(&id), Ok(numbers.clone()));
+	assert_eq!(sqids.try_decode(&id.replace('-', "")), Ok(numbers));
+}
+
+#[test]
+fn invalid_group() {
+	assert_eq!(Sqids::builder().group(0, '-').build().unwrap_err(), Error::GroupSize);
+	assert_eq!(Sqids::builder().group(4, 'a').build().unwrap_err(), Error::GroupSeparator);
+}
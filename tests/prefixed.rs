@@ -0,0 +1,65 @@
+use sqids::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Entity {
+	User,
+	Invoice,
+	Order,
+}
+
+fn prefixed_sqids() -> PrefixedSqids<Entity> {
+	let mut sqids = PrefixedSqids::new(Sqids::default());
+	sqids.register(Entity::User, "usr_").unwrap();
+	sqids.register(Entity::Invoice, "inv_").unwrap();
+	sqids
+}
+
+#[test]
+fn simple() {
+	let sqids = prefixed_sqids();
+
+	let numbers = vec![1, 2, 3];
+	let id = "usr_86Rf07";
+
+	assert_eq!(sqids.encode(Entity::User, &numbers).unwrap(), id);
+	assert_eq!(sqids.decode(id).unwrap(), (Entity::User, numbers.clone()));
+	assert_eq!(sqids.decode("inv_86Rf07").unwrap(), (Entity::Invoice, numbers));
+}
+
+#[test]
+fn unknown_prefix() {
+	let sqids = prefixed_sqids();
+
+	assert_eq!(sqids.encode(Entity::Order, &[1]), Err(Error::PrefixUnknown));
+	assert_eq!(sqids.decode("ord_86Rf07"), Err(Error::PrefixUnknown));
+	assert_eq!(sqids.decode("86Rf07"), Err(Error::PrefixUnknown));
+}
+
+#[test]
+fn invalid_id() {
+	let sqids = prefixed_sqids();
+
+	assert_eq!(sqids.decode("usr_"), Err(Error::InvalidId));
+	assert_eq!(sqids.decode("usr_86Rf_07"), Err(Error::InvalidId));
+}
+
+#[test]
+fn ambiguous_prefixes() {
+	let mut sqids = prefixed_sqids();
+
+	assert_eq!(sqids.register(Entity::User, "user_"), Err(Error::PrefixAmbiguous));
+	assert_eq!(sqids.register(Entity::Order, "usr_"), Err(Error::PrefixAmbiguous));
+	assert_eq!(sqids.register(Entity::Order, "usr_o_"), Err(Error::PrefixAmbiguous));
+	assert_eq!(sqids.register(Entity::Order, "in"), Err(Error::PrefixDelimiter));
+	assert_eq!(sqids.register(Entity::Order, "in_"), Ok(()));
+}
+
+#[test]
+fn prefix_delimiter() {
+	let mut sqids = PrefixedSqids::new(Sqids::default());
+
+	assert_eq!(sqids.register(Entity::User, ""), Err(Error::PrefixDelimiter));
+	assert_eq!(sqids.register(Entity::User, "usr"), Err(Error::PrefixDelimiter));
+	assert_eq!(sqids.register(Entity::User, "usr:"), Ok(()));
+	assert_eq!(sqids.prefix(Entity::User), Some("usr:"));
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "registry")]
+
+use serde::{de::DeserializeSeed, Deserialize, Serialize};
+use sqids::{
+	serde::{SerializeWith, SqidsSeed},
+	Error, Sqid, Sqids, SqidsConfig,
+};
+use std::thread;
+
+#[test]
+fn register() {
+	sqids::register("register", Sqids::builder().min_length(8).build().unwrap()).unwrap();
+
+	assert_eq!(sqids::get("register").unwrap().encode(&[1]).unwrap(), "UkLWZg9D");
+	assert!(sqids::get("unknown").is_none());
+}
+
+#[test]
+fn already_registered() {
+	sqids::register("already_registered", Sqids::default()).unwrap();
+
+	assert_eq!(
+		sqids::register("already_registered", Sqids::default()),
+		Err(Error::AlreadyRegistered)
+	);
+}
+
+#[test]
+fn concurrent() {
+	let names: Vec<_> = (0..16).map(|i| format!("concurrent_{i}")).collect();
+
+	thread::scope(|scope| {
+		for name in &names {
+			scope.spawn(|| sqids::register(name, Sqids::default()).unwrap());
+		}
+	});
+
+	assert!(names.iter().all(|name| sqids::get(name).is_some()));
+}
+
+#[test]
+fn serde() {
+	sqids::register("serde", Sqids::builder().min_length(8).build().unwrap()).unwrap();
+
+	let json = serde_json::to_string(&SerializeWith::registered("serde", &1).unwrap()).unwrap();
+	assert_eq!(json, r#""UkLWZg9D""#);
+
+	let number = SqidsSeed::<u64>::registered("serde")
+		.unwrap()
+		.deserialize(&mut serde_json::Deserializer::from_str(&json))
+		.unwrap();
+	assert_eq!(number, 1);
+
+	assert!(SqidsSeed::<u64>::registered("unknown").is_none());
+}
+
+sqids::serde_registered!(mod serde_with = "serde_with");
+sqids::serde_registered!(mod serde_unknown = "serde_unknown");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Invoice {
+	#[serde(with = "serde_with::as_sqid")]
+	id: u64,
+	#[serde(with = "serde_with::as_sqid_option")]
+	order_id: Option<u64>,
+	#[serde(with = "serde_with::as_sqid_vec")]
+	item_ids: Vec<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Unknown(#[serde(with = "serde_unknown::as_sqid")] u64);
+
+#[test]
+fn serde_with() {
+	sqids::register("serde_with", Sqids::builder().min_length(8).build().unwrap()).unwrap();
+
+	let invoice = Invoice { id: 1, order_id: Some(2), item_ids: vec![3] };
+	let json = r#"{"id":"UkLWZg9D","order_id":"gbHJdmfr","item_ids":["EfhxLZ9c"]}"#;
+	assert_eq!(serde_json::to_string(&invoice).unwrap(), json);
+	assert_eq!(serde_json::from_str::<Invoice>(json).unwrap(), invoice);
+
+	let invoice = Invoice { id: 1, order_id: None, item_ids: vec![] };
+	let json = r#"{"id":"UkLWZg9D","order_id":null,"item_ids":[]}"#;
+	assert_eq!(serde_json::to_string(&invoice).unwrap(), json);
+	assert_eq!(serde_json::from_str::<Invoice>(json).unwrap(), invoice);
+
+	// not canonical with the registered Sqids
+	assert!(
+		serde_json::from_str::<Invoice>(r#"{"id":"Uk","order_id":null,"item_ids":[]}"#).is_err()
+	);
+
+	let error = serde_json::to_string(&Unknown(1)).unwrap_err();
+	assert!(error.to_string().contains("registered under the name"), "{error}");
+	assert!(serde_json::from_str::<Unknown>(r#""Uk""#).is_err());
+}
+
+struct Order;
+
+impl SqidsConfig for Order {
+	fn sqids() -> &'static Sqids {
+		sqids::get("typed").expect("typed sqids are registered")
+	}
+}
+
+#[test]
+fn typed() {
+	sqids::register("typed", Sqids::builder().min_length(8).build().unwrap()).unwrap();
+
+	assert_eq!(Sqid::<Order>::from(1).to_string(), "UkLWZg9D");
+	assert_eq!("UkLWZg9D".parse::<Sqid<Order>>(), Ok(Sqid::from(1)));
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "rocket")]
+
+use rocket::{
+	form::Form,
+	get,
+	http::{ContentType, Status},
+	local::blocking::Client,
+	post, routes, uri, FromForm,
+};
+use sqids::{Sqid, Sqids, SqidsConfig};
+use std::sync::LazyLock;
+
+struct Order;
+
+impl SqidsConfig for Order {
+	fn sqids() -> &'static Sqids {
+		static SQIDS: LazyLock<Sqids> =
+			LazyLock::new(|| Sqids::builder().min_length(8).build().unwrap());
+		&SQIDS
+	}
+}
+
+#[get("/orders/<id>")]
+fn order(id: Sqid<Order>) -> String {
+	format!("{:?}", id.numbers())
+}
+
+#[get("/search?<order>")]
+fn search(order: Sqid<Order>) -> String {
+	format!("{:?}", order.numbers())
+}
+
+#[derive(FromForm)]
+struct Update {
+	order: Sqid<Order>,
+}
+
+#[post("/update", data = "<update>")]
+fn update(update: Form<Update>) -> String {
+	format!("{:?}", update.order.numbers())
+}
+
+fn client() -> Client {
+	Client::tracked(rocket::build().mount("/", routes![order, search, update])).unwrap()
+}
+
+#[test]
+fn param() {
+	let client = client();
+
+	assert_eq!(client.get("/orders/UkLWZg9D").dispatch().into_string().unwrap(), "[1]");
+	assert_eq!(client.get("/orders/86Rf07xd").dispatch().into_string().unwrap(), "[1, 2, 3]");
+	// not canonical and invalid characters
+	assert_eq!(client.get("/orders/Uk").dispatch().status(), Status::UnprocessableEntity);
+	assert_eq!(client.get("/orders/Uk-LWZg9D").dispatch().status(), Status::UnprocessableEntity);
+}
+
+#[test]
+fn form_field() {
+	let client = client();
+
+	assert_eq!(client.get("/search?order=UkLWZg9D").dispatch().into_string().unwrap(), "[1]");
+	assert_eq!(client.get("/search?order=Uk").dispatch().status(), Status::UnprocessableEntity);
+
+	let response =
+		client.post("/update").header(ContentType::Form).body("order=86Rf07xd").dispatch();
+	assert_eq!(response.into_string().unwrap(), "[1, 2, 3]");
+}
+
+#[test]
+fn uri() {
+	let id = Sqid::<Order>::from(1);
+
+	assert_eq!(uri!(order(&id)).to_string(), "/orders/UkLWZg9D");
+	assert_eq!(uri!(search(id)).to_string(), "/search?order=UkLWZg9D");
+}
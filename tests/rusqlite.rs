@@ -0,0 +1,157 @@
+#![cfg(feature = "rusqlite")]
+
+use rusqlite::Connection;
+use sqids::Sqids;
+
+fn connection() -> Connection {
+	let connection = Connection::open_in_memory().unwrap();
+	sqids::rusqlite::register_functions(&connection).unwrap();
+	connection
+}
+
+fn query<T: rusqlite::types::FromSql>(connection: &Connection, sql: &str) -> rusqlite::Result<T> {
+	connection.query_row(sql, [], |row| row.get(0))
+}
+
+#[test]
+fn encode() {
+	let connection = connection();
+
+	assert_eq!(
+		query::<String>(&connection, "SELECT sqids_encode('{}', 1, 2, 3)").unwrap(),
+		"86Rf07"
+	);
+	assert_eq!(query::<String>(&connection, "SELECT sqids_encode(NULL, 1)").unwrap(), "Uk");
+	assert_eq!(
+		query::<String>(&connection, r#"SELECT sqids_encode('{"min_length": 8}', 1, 2, 3)"#)
+			.unwrap(),
+		"86Rf07xd"
+	);
+	assert_eq!(
+		query::<String>(
+			&connection,
+			r#"SELECT sqids_encode('{"alphabet": "0123456789abcdef"}', 1)"#
+		)
+		.unwrap(),
+		Sqids::builder()
+			.alphabet("0123456789abcdef".chars().collect())
+			.build()
+			.unwrap()
+			.encode(&[1])
+			.unwrap()
+	);
+	assert_eq!(
+		query::<Option<String>>(&connection, "SELECT sqids_encode('{}', 1, NULL)").unwrap(),
+		None
+	);
+}
+
+#[test]
+fn decode() {
+	let connection = connection();
+
+	assert_eq!(
+		query::<String>(&connection, "SELECT sqids_decode('{}', '86Rf07')").unwrap(),
+		"[1,2,3]"
+	);
+	assert_eq!(
+		query::<String>(&connection, r#"SELECT sqids_decode('{"min_length": 8}', '86Rf07xd')"#)
+			.unwrap(),
+		"[1,2,3]"
+	);
+	assert_eq!(
+		query::<Option<String>>(&connection, "SELECT sqids_decode('{}', NULL)").unwrap(),
+		None
+	);
+}
+
+#[test]
+fn decode_each() {
+	let connection = connection();
+
+	let mut statement =
+		connection.prepare("SELECT value FROM sqids_decode_each(NULL, '86Rf07')").unwrap();
+	let numbers: Vec<i64> =
+		statement.query_map([], |row| row.get(0)).unwrap().map(Result::unwrap).collect();
+	assert_eq!(numbers, [1, 2, 3]);
+
+	let total: i64 = query(
+		&connection,
+		r#"SELECT sum(value) FROM sqids_decode_each('{"min_length": 8}', '86Rf07xd')"#,
+	)
+	.unwrap();
+	assert_eq!(total, 6);
+
+	// the default configuration when only the sqid is given
+	let total: i64 =
+		query(&connection, "SELECT sum(value) FROM sqids_decode_each('86Rf07')").unwrap();
+	assert_eq!(total, 6);
+
+	let count: i64 =
+		query(&connection, "SELECT count(*) FROM sqids_decode_each('{}', NULL)").unwrap();
+	assert_eq!(count, 0);
+}
+
+#[test]
+fn many_configurations() {
+	let connection = connection();
+
+	for _ in 0..2 {
+		for min_length in 0..3 * sqids::rusqlite::MAX_CACHED_CONFIGS {
+			let config = format!(r#"{{"min_length": {min_length}}}"#);
+			let id: String = connection
+				.query_row("SELECT sqids_encode(?1, 1)", [&config], |row| row.get(0))
+				.unwrap();
+			assert_eq!(id.len(), min_length.max(2));
+
+			let number: i64 = connection
+				.query_row("SELECT value FROM sqids_decode_each(?1, ?2)", [&config, &id], |row| {
+					row.get(0)
+				})
+				.unwrap();
+			assert_eq!(number, 1);
+		}
+	}
+}
+
+#[test]
+fn join() {
+	let connection = connection();
+	connection
+		.execute_batch(
+			"CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT);
+			INSERT INTO items VALUES (1, 'one'), (2, 'two'), (3, 'three');",
+		)
+		.unwrap();
+
+	let name: String = query(
+		&connection,
+		"SELECT name FROM items WHERE id = (SELECT value FROM sqids_decode_each('{}', sqids_encode('{}', 2)))",
+	)
+	.unwrap();
+	assert_eq!(name, "two");
+
+	let ids: String = query(
+		&connection,
+		"SELECT group_concat(sqids_encode('{}', id), ',') FROM (SELECT id FROM items ORDER BY id)",
+	)
+	.unwrap();
+	assert_eq!(ids, "Uk,gb,Ef");
+}
+
+#[test]
+fn errors() {
+	let connection = connection();
+
+	for sql in [
+		"SELECT sqids_encode()",
+		"SELECT sqids_encode('{}', -1)",
+		"SELECT sqids_encode('[]', 1)",
+		r#"SELECT sqids_encode('{"alphabet": "ab"}', 1)"#,
+		r#"SELECT sqids_encode('{"salt": "x"}', 1)"#,
+		"SELECT sqids_decode('{}', 'Uk!')",
+		"SELECT value FROM sqids_decode_each('{}', 'Uk!')",
+	] {
+		assert!(query::<rusqlite::types::Value>(&connection, sql).is_err(), "{sql}");
+	}
+}
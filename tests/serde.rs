@@ -0,0 +1,91 @@
+use serde::{de::DeserializeSeed, Deserialize, Serialize};
+use sqids::{
+	serde::{SerializeWith, SqidsSeed},
+	Sqids,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Order {
+	#[serde(with = "sqids::serde::as_sqid")]
+	id: u64,
+	#[serde(with = "sqids::serde::as_sqid_option")]
+	parent_id: Option<u64>,
+	#[serde(with = "sqids::serde::as_sqid_vec")]
+	item_ids: Vec<u64>,
+}
+
+#[test]
+fn roundtrip() {
+	let order = Order { id: 1, parent_id: Some(u64::MAX), item_ids: vec![2, 3] };
+	let json = r#"{"id":"Uk","parent_id":"eIkvoXH40Lmd","item_ids":["gb","Ef"]}"#;
+
+	assert_eq!(serde_json::to_string(&order).unwrap(), json);
+	assert_eq!(serde_json::from_str::<Order>(json).unwrap(), order);
+}
+
+#[test]
+fn none() {
+	let order = Order { id: 1, parent_id: None, item_ids: vec![] };
+	let json = r#"{"id":"Uk","parent_id":null,"item_ids":[]}"#;
+
+	assert_eq!(serde_json::to_string(&order).unwrap(), json);
+	assert_eq!(serde_json::from_str::<Order>(json).unwrap(), order);
+}
+
+#[test]
+fn invalid() {
+	let error = |json| serde_json::from_str::<Order>(json).unwrap_err().to_string();
+
+	assert!(error(r#"{"id":"U_k","parent_id":null,"item_ids":[]}"#).contains("invalid sqid"));
+	assert!(error(r#"{"id":"86Rf07","parent_id":null,"item_ids":[]}"#).contains("invalid sqid"));
+	assert!(error(r#"{"id":"Uk","parent_id":"se8ojk","item_ids":[]}"#).contains("different ID"));
+	assert!(error(r#"{"id":1,"parent_id":null,"item_ids":[]}"#).contains("expected a sqid"));
+}
+
+#[test]
+fn seed() {
+	let sqids = Sqids::builder().min_length(8).build().unwrap();
+	let deserialize = |json| serde_json::Deserializer::from_str(json);
+
+	let number =
+		SqidsSeed::<u64>::new(&sqids).deserialize(&mut deserialize(r#""UkLWZg9D""#)).unwrap();
+	assert_eq!(number, 1);
+
+	let numbers =
+		SqidsSeed::<[u64; 3]>::new(&sqids).deserialize(&mut deserialize(r#""86Rf07xd""#)).unwrap();
+	assert_eq!(numbers, [1, 2, 3]);
+
+	let numbers =
+		SqidsSeed::<Vec<u64>>::new(&sqids).deserialize(&mut deserialize(r#""86Rf07xd""#)).unwrap();
+	assert_eq!(numbers, [1, 2, 3]);
+}
+
+#[test]
+fn seed_errors() {
+	let sqids = Sqids::builder().min_length(8).build().unwrap();
+	let deserialize = |json| serde_json::Deserializer::from_str(json);
+
+	let error = SqidsSeed::<[u64; 2]>::new(&sqids)
+		.deserialize(&mut deserialize(r#""86Rf07xd""#))
+		.unwrap_err();
+	assert!(error.to_string().contains("invalid length 3, expected a sqid of 2 numbers"));
+
+	// shorter than the minimum length, so not canonical
+	let error = SqidsSeed::<u64>::new(&sqids).deserialize(&mut deserialize(r#""Uk""#)).unwrap_err();
+	assert!(error.to_string().contains("invalid sqid"));
+}
+
+#[test]
+fn serialize_with() {
+	let sqids = Sqids::builder().min_length(8).build().unwrap();
+
+	assert_eq!(serde_json::to_string(&SerializeWith::new(&sqids, &1)).unwrap(), r#""UkLWZg9D""#);
+	assert_eq!(
+		serde_json::to_string(&SerializeWith::new(&sqids, &[1, 2, 3])).unwrap(),
+		r#""86Rf07xd""#
+	);
+	assert_eq!(
+		serde_json::to_string(&SerializeWith::new(&sqids, &vec![1, 2, 3])).unwrap(),
+		r#""86Rf07xd""#
+	);
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "sqlx")]
+
+use sqids::{sqlx::SqidText, Sqid, Sqids, SqidsConfig};
+use sqlx::{sqlite::SqlitePool, Row};
+use std::sync::LazyLock;
+
+struct Order;
+
+impl SqidsConfig for Order {
+	fn sqids() -> &'static Sqids {
+		static SQIDS: LazyLock<Sqids> =
+			LazyLock::new(|| Sqids::builder().min_length(8).build().unwrap());
+		&SQIDS
+	}
+}
+
+struct Blocked;
+
+impl SqidsConfig for Blocked {
+	fn sqids() -> &'static Sqids {
+		// every sqid of 0 is blocked
+		static SQIDS: LazyLock<Sqids> = LazyLock::new(|| {
+			Sqids::builder()
+				.alphabet("abc".chars().collect())
+				.min_length(3)
+				.blocklist(["cab", "abc", "bca"].map(String::from).into())
+				.build()
+				.unwrap()
+		});
+		&SQIDS
+	}
+}
+
+async fn pool() -> SqlitePool {
+	let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+	sqlx::query("CREATE TABLE orders (id INT8 PRIMARY KEY NOT NULL, public_id TEXT)")
+		.execute(&pool)
+		.await
+		.unwrap();
+	pool
+}
+
+#[tokio::test]
+async fn number() {
+	let pool = pool().await;
+	let id: Sqid<Order> = "UkLWZg9D".parse().unwrap();
+
+	sqlx::query("INSERT INTO orders (id) VALUES (?)").bind(&id).execute(&pool).await.unwrap();
+
+	let number: i64 = sqlx::query_scalar("SELECT id FROM orders").fetch_one(&pool).await.unwrap();
+	assert_eq!(number, 1);
+
+	let row =
+		sqlx::query("SELECT id FROM orders WHERE id = ?").bind(&id).fetch_one(&pool).await.unwrap();
+	assert_eq!(row.get::<Sqid<Order>, _>("id"), id);
+	assert_eq!(row.get::<Sqid<Order>, _>("id").to_string(), "UkLWZg9D");
+}
+
+#[tokio::test]
+async fn text() {
+	let pool = pool().await;
+	let id = SqidText(Sqid::<Order>::from(vec![1, 2, 3]));
+
+	sqlx::query("INSERT INTO orders (id, public_id) VALUES (1, ?)")
+		.bind(&id)
+		.execute(&pool)
+		.await
+		.unwrap();
+
+	let text: String =
+		sqlx::query_scalar("SELECT public_id FROM orders").fetch_one(&pool).await.unwrap();
+	assert_eq!(text, "86Rf07xd");
+
+	let decoded: SqidText<Sqid<Order>> =
+		sqlx::query_scalar("SELECT public_id FROM orders").fetch_one(&pool).await.unwrap();
+	assert_eq!(decoded, id);
+}
+
+#[tokio::test]
+async fn invalid() {
+	let pool = pool().await;
+
+	for id in [Sqid::<Order>::from(vec![1, 2]), Sqid::from(u64::MAX)] {
+		assert!(sqlx::query("INSERT INTO orders (id) VALUES (?)")
+			.bind(id)
+			.execute(&pool)
+			.await
+			.is_err());
+	}
+
+	// negative numbers and non-canonical sqids are rejected when read
+	sqlx::query("INSERT INTO orders (id, public_id) VALUES (-1, 'Uk')")
+		.execute(&pool)
+		.await
+		.unwrap();
+	let number =
+		sqlx::query_scalar::<_, Sqid<Order>>("SELECT id FROM orders").fetch_one(&pool).await;
+	assert!(number.is_err());
+	let text = sqlx::query_scalar::<_, SqidText<Sqid<Order>>>("SELECT public_id FROM orders")
+		.fetch_one(&pool)
+		.await;
+	assert!(text.is_err());
+}
+
+#[tokio::test]
+async fn blocked() {
+	let pool = pool().await;
+
+	let result = sqlx::query("INSERT INTO orders (id, public_id) VALUES (1, ?)")
+		.bind(SqidText(Sqid::<Blocked>::from(0)))
+		.execute(&pool)
+		.await;
+	assert!(result.is_err());
+}
@@ -0,0 +1,57 @@
+use sqids::*;
+use std::{collections::HashSet, sync::LazyLock};
+
+struct User;
+
+impl SqidsConfig for User {
+	fn sqids() -> &'static Sqids {
+		static SQIDS: LazyLock<Sqids> = LazyLock::new(Sqids::default);
+		&SQIDS
+	}
+}
+
+struct Order;
+
+impl SqidsConfig for Order {
+	fn sqids() -> &'static Sqids {
+		static SQIDS: LazyLock<Sqids> =
+			LazyLock::new(|| Sqids::builder().min_length(10).build().unwrap());
+		&SQIDS
+	}
+}
+
+#[test]
+fn display_and_parse() {
+	let id = Sqid::<User>::new(vec![1, 2, 3]);
+
+	assert_eq!(id.to_string(), "86Rf07");
+	assert_eq!("86Rf07".parse::<Sqid<User>>().unwrap(), id);
+	assert_eq!(id.numbers(), [1, 2, 3]);
+}
+
+#[test]
+fn configuration_per_type() {
+	let user = Sqid::<User>::from(vec![1, 2, 3]);
+	let order = Sqid::<Order>::from(vec![1, 2, 3]);
+
+	assert_eq!(user.to_string(), "86Rf07");
+	assert_eq!(order.to_string(), "86Rf07xd4z");
+	assert_eq!("86Rf07xd4z".parse::<Sqid<Order>>().unwrap().into_numbers(), vec![1, 2, 3]);
+}
+
+#[test]
+fn parse_errors() {
+	assert_eq!("".parse::<Sqid<User>>(), Err(Error::InvalidId));
+	assert_eq!("86Rf_07".parse::<Sqid<User>>(), Err(Error::InvalidId));
+	assert_eq!("se8ojk".parse::<Sqid<User>>(), Err(Error::NonCanonicalId));
+}
+
+#[test]
+fn eq_hash_ord() {
+	let ids: HashSet<Sqid<User>> = [Sqid::from(1), Sqid::from(1), Sqid::from(2)].into();
+	assert_eq!(ids.len(), 2);
+
+	let mut ids = vec![Sqid::<User>::from(3), Sqid::from(1), Sqid::from(vec![1, 0])];
+	ids.sort();
+	assert_eq!(ids, [Sqid::from(1), Sqid::from(vec![1, 0]), Sqid::from(3)]);
+}
@@ -0,0 +1,89 @@
+use sqids::{Error, FromSqidNumbers, Sqids, ToSqidNumbers};
+
+#[test]
+fn encode_value() {
+	let sqids = Sqids::default();
+	let id = sqids.encode(&[1, 2, 3]).unwrap();
+
+	assert_eq!(sqids.encode_value(&(1, 2, 3)).unwrap(), id);
+	assert_eq!(sqids.encode_value(&[1, 2, 3]).unwrap(), id);
+	assert_eq!(sqids.encode_value(&vec![1, 2, 3]).unwrap(), id);
+	assert_eq!(sqids.encode_value(&[1, 2, 3][..]).unwrap(), id);
+	assert_eq!(sqids.encode_value(&1).unwrap(), sqids.encode(&[1]).unwrap());
+}
+
+#[test]
+fn decode_value() {
+	let sqids = Sqids::default();
+	let id = sqids.encode(&[1, 2, 3]).unwrap();
+
+	assert_eq!(sqids.decode_value::<(u64, u64, u64)>(&id), Ok((1, 2, 3)));
+	assert_eq!(sqids.decode_value::<[u64; 3]>(&id), Ok([1, 2, 3]));
+	assert_eq!(sqids.decode_value::<Vec<u64>>(&id), Ok(vec![1, 2, 3]));
+}
+
+#[test]
+fn value_roundtrip() {
+	let sqids = Sqids::builder().min_length(10).build().unwrap();
+	let value = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+
+	let id = sqids.encode_value(&value).unwrap();
+	assert_eq!(sqids.decode_value(&id), Ok(value));
+}
+
+#[test]
+fn decode_value_arity() {
+	let sqids = Sqids::default();
+	let id = sqids.encode(&[1, 2, 3]).unwrap();
+
+	assert_eq!(sqids.decode_value::<u64>(&id), Err(Error::Arity { expected: 1, actual: 3 }));
+	assert_eq!(
+		sqids.decode_value::<(u64, u64, u64, u64)>(&id),
+		Err(Error::Arity { expected: 4, actual: 3 })
+	);
+	assert_eq!(sqids.decode_value::<[u64; 2]>(&id), Err(Error::Arity { expected: 2, actual: 3 }));
+}
+
+#[test]
+fn decode_value_invalid() {
+	let sqids = Sqids::default();
+
+	assert_eq!(sqids.decode_value::<u64>(""), Err(Error::InvalidId));
+	assert_eq!(sqids.decode_value::<Vec<u64>>(""), Err(Error::InvalidId));
+	assert_eq!(sqids.decode_value::<u64>("*"), Err(Error::InvalidId));
+	assert_eq!(sqids.decode_value::<(u64, u64, u64)>("se8ojk"), Err(Error::NonCanonicalId));
+}
+
+#[test]
+fn custom_value() {
+	#[derive(Debug, PartialEq)]
+	struct ItemKey {
+		tenant_id: u64,
+		project_id: u64,
+		item_id: u64,
+	}
+
+	impl ToSqidNumbers for ItemKey {
+		fn to_sqid_numbers(&self) -> impl ExactSizeIterator<Item = u64> + Clone {
+			[self.tenant_id, self.project_id, self.item_id].into_iter()
+		}
+	}
+
+	impl FromSqidNumbers for ItemKey {
+		fn from_sqid_numbers(numbers: Vec<u64>) -> sqids::Result<Self> {
+			let (tenant_id, project_id, item_id) = FromSqidNumbers::from_sqid_numbers(numbers)?;
+			Ok(ItemKey { tenant_id, project_id, item_id })
+		}
+	}
+
+	let sqids = Sqids::default();
+	let key = ItemKey { tenant_id: 1, project_id: 2, item_id: 3 };
+
+	let id = sqids.encode_value(&key).unwrap();
+	assert_eq!(id, sqids.encode(&[1, 2, 3]).unwrap());
+	assert_eq!(sqids.decode_value::<ItemKey>(&id), Ok(key));
+	assert_eq!(
+		sqids.decode_value::<ItemKey>(&sqids.encode(&[1]).unwrap()),
+		Err(Error::Arity { expected: 3, actual: 1 })
+	);
+}